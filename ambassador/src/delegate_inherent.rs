@@ -0,0 +1,98 @@
+use crate::delegate_to_methods::check_for_method_impls_and_extras;
+use crate::register::build_method_invocation;
+use crate::util::{error, process_results};
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, ToTokens};
+use syn::parse::{ParseStream, Parser};
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, parse_quote, Error, Ident, ImplItem, ItemImpl, LitStr, Result, Token,
+};
+
+/// Parses the `target = "field"` argument of `#[delegate_inherent]`
+fn parse_target(attr: TokenStream2) -> Result<syn::Member> {
+    if attr.is_empty() {
+        return error!(
+            Span::call_site(),
+            "#[delegate_inherent] needs the field to delegate to, eg. #[delegate_inherent(target = \"inner\")]"
+        );
+    }
+    let parser = |input: ParseStream<'_>| {
+        let key: Ident = input.parse()?;
+        if key != "target" {
+            return error!(
+                key.span(),
+                "invalid key for #[delegate_inherent] attribute, expected \"target\""
+            );
+        }
+        let _: Token![=] = input.parse()?;
+        let lit: LitStr = input.parse()?;
+        let _: Option<Token![,]> = input.parse()?;
+        lit.parse()
+    };
+    parser.parse2(attr)
+}
+
+/// Replaces the declared method by one calling the method of the same name on the target field
+fn forward_method(method: &syn::ImplItemMethod, target: &syn::Member) -> Result<ImplItem> {
+    let sig = &method.sig;
+    if sig.receiver().is_none() {
+        return error!(
+            sig.ident.span(),
+            "function `{}` has no receiver, only methods taking `self`, `&self` or `&mut self` can be delegated by #[delegate_inherent]",
+            sig.ident
+        );
+    }
+    // The arguments are passed on by name, so they can't be patterns
+    let arguments = process_results(
+        sig.inputs.iter().filter_map(|arg| match arg {
+            syn::FnArg::Receiver(_) => None,
+            syn::FnArg::Typed(pat_type) => Some(match &*pat_type.pat {
+                syn::Pat::Ident(pat_ident) if pat_ident.subpat.is_none() => {
+                    Ok(pat_ident.ident.clone())
+                }
+                pat => error!(
+                    pat.span(),
+                    "arguments of methods delegated by #[delegate_inherent] have to be named"
+                ),
+            }),
+        }),
+        |iter| iter.collect::<Vec<_>>(),
+    )?;
+    let invocation = build_method_invocation(sig, &quote!(self.#target), &quote!(#(#arguments),*));
+    let attrs = &method.attrs;
+    let vis = &method.vis;
+    Ok(parse_quote! {
+        #(#attrs)*
+        #vis #sig {
+            #invocation
+        }
+    })
+}
+
+fn expand(attr: TokenStream2, mut input: ItemImpl) -> Result<TokenStream2> {
+    let target = parse_target(attr)?;
+    if let Some((_, path, _)) = &input.trait_ {
+        return error!(
+            path.span(),
+            "#[delegate_inherent] can only be used on inherent impl blocks, traits are delegated with #[derive(Delegate)]"
+        );
+    }
+    check_for_method_impls_and_extras(&input.items)?;
+    input.items = process_results(
+        input.items.iter().map(|item| match item {
+            ImplItem::Method(method) => forward_method(method, &target),
+            _ => unreachable!("only methods are left after checking the impl block"),
+        }),
+        |iter| iter.collect(),
+    )?;
+    Ok(input.into_token_stream())
+}
+
+pub fn delegate_macro(attr: TokenStream, input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ItemImpl);
+    expand(attr.into(), input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
@@ -1,7 +1,7 @@
-use crate::util::{error, process_results};
-use itertools::Itertools;
-use proc_macro2::{Ident, TokenStream as TokenStream2};
-use quote::ToTokens;
+use crate::register;
+use crate::util::{error, process_results, ReceiverType};
+use proc_macro2::{Delimiter, Group, Ident, TokenStream as TokenStream2, TokenTree};
+use quote::{quote, ToTokens};
 use std::cmp::Ordering;
 use syn::ext::IdentExt;
 use syn::parse::{ParseStream, Parser};
@@ -9,12 +9,22 @@ use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::Comma;
 use syn::{
-    parse_quote, Error, GenericParam, Generics, ImplGenerics, LitBool, LitStr, PathArguments,
-    Result, Token, WhereClause, WherePredicate,
+    parse_quote, Error, GenericArgument, GenericParam, Generics, ImplGenerics, LitBool, LitInt,
+    LitStr, PathArguments, Result, Token, TypeParamBound, WhereClause, WherePredicate,
 };
 
 pub(super) trait DelegateTarget: Default {
     fn try_update(&mut self, key: &str, lit: LitStr) -> Option<Result<()>>;
+
+    /// Like `try_update` but for flags without a value (eg. `#[delegate(Trait, flag)]`)
+    fn try_flag(&mut self, _key: &str) -> Option<Result<()>> {
+        None
+    }
+
+    /// Like `try_update` but for lists (eg. `#[delegate(Trait, key(..))]`)
+    fn try_list(&mut self, _key: &str, _items: ParseStream<'_>) -> Option<Result<()>> {
+        None
+    }
 }
 
 #[derive(Default)]
@@ -23,6 +33,18 @@ pub(super) struct DelegateArgs<T: DelegateTarget> {
     pub(crate) where_clauses: Punctuated<WherePredicate, Comma>,
     pub(crate) generics: Vec<GenericParam>,
     pub(crate) inhibit_automatic_where_clause: bool,
+    pub(crate) excluded: Vec<Ident>,
+    pub(crate) included: Option<Vec<Ident>>,
+    pub(crate) inline: bool,
+    pub(crate) doc: bool,
+    pub(crate) receivers: Option<Vec<ReceiverType>>,
+    pub(crate) only_required: bool,
+    pub(crate) unsafe_impl: bool,
+    pub(crate) const_impl: bool,
+    pub(crate) from_inner: Option<syn::Expr>,
+    pub(crate) auto_bounds: Punctuated<TypeParamBound, syn::token::Add>,
+    pub(crate) cfg: Option<syn::Meta>,
+    pub(crate) registered_as: Option<Ident>,
 }
 
 impl<T: DelegateTarget> DelegateArgs<T> {
@@ -39,6 +61,63 @@ impl<T: DelegateTarget> DelegateArgs<T> {
                     lit.parse_with(Punctuated::<GenericParam, Comma>::parse_terminated)?;
                 self.generics.extend(generics_val);
             }
+            "from_inner" => {
+                if self.from_inner.is_some() {
+                    return error!(
+                        lit.span(),
+                        "\"from_inner\" value for delegate attribute can only be specified once"
+                    );
+                }
+                self.from_inner = Some(lit.parse()?);
+            }
+            "cfg" => {
+                if self.cfg.is_some() {
+                    return error!(
+                        lit.span(),
+                        "\"cfg\" value for delegate attribute can only be specified once"
+                    );
+                }
+                self.cfg = Some(lit.parse()?);
+            }
+            "as" => {
+                if self.registered_as.is_some() {
+                    return error!(
+                        lit.span(),
+                        "\"as\" value for delegate attribute can only be specified once"
+                    );
+                }
+                self.registered_as = Some(lit.parse()?);
+            }
+            "auto_bounds" => {
+                let bounds =
+                    lit.parse_with(Punctuated::<TypeParamBound, Token![+]>::parse_terminated)?;
+                self.auto_bounds.extend(bounds);
+            }
+            "receivers" => {
+                let names = lit.parse_with(|input: ParseStream<'_>| {
+                    Punctuated::<Ident, Comma>::parse_terminated_with(input, Ident::parse_any)
+                })?;
+                let receivers = process_results(
+                    names.iter().map(|name| match &*name.to_string() {
+                        "value" => Ok(ReceiverType::Owned),
+                        "ref" => Ok(ReceiverType::Ref),
+                        "ref_mut" => Ok(ReceiverType::MutRef),
+                        _ => error!(
+                            name.span(),
+                            "unknown receiver `{}` in \"receivers\" value on #[delegate] attribute, expected `value`, `ref` or `ref_mut`",
+                            name
+                        ),
+                    }),
+                    |iter| iter.collect::<Vec<_>>(),
+                )?;
+                if receivers.is_empty() {
+                    return error!(
+                        lit.span(),
+                        "\"receivers\" value on #[delegate] attribute has to list at least one of `value`, `ref` or `ref_mut`"
+                    );
+                }
+                self.set_receivers(lit.span(), receivers)?;
+            }
             "automatic_where_clause" => {
                 let auto_where_val: LitBool = lit.parse()?;
                 self.inhibit_automatic_where_clause = !auto_where_val.value;
@@ -50,6 +129,142 @@ impl<T: DelegateTarget> DelegateArgs<T> {
         }
         Ok(())
     }
+
+    fn add_flag(&mut self, key: Ident) -> Result<()> {
+        let span = key.span();
+        match &*key.to_string() {
+            "inline" => self.inline = true,
+            "doc" => self.doc = true,
+            "ref_only" => self.set_receivers(span, vec![ReceiverType::Ref])?,
+            "only_required" => self.only_required = true,
+            "unsafe" => self.unsafe_impl = true,
+            "const" => self.const_impl = true,
+            "no_auto_bound" => self.inhibit_automatic_where_clause = true,
+            key => self
+                .target
+                .try_flag(key)
+                .unwrap_or_else(|| error!(span, "invalid flag for a delegate attribute"))?,
+        }
+        Ok(())
+    }
+
+    /// Restricts the delegated methods to those with the given receivers, from either `ref_only` or `receivers`
+    fn set_receivers(
+        &mut self,
+        span: proc_macro2::Span,
+        receivers: Vec<ReceiverType>,
+    ) -> Result<()> {
+        if self.receivers.is_some() {
+            return error!(
+                span,
+                "\"ref_only\" flag and \"receivers\" value on #[delegate] attribute can only be specified once and not together"
+            );
+        }
+        self.receivers = Some(receivers);
+        Ok(())
+    }
+
+    /// Attributes added to every generated method
+    pub fn method_attrs(&self, trait_ident: &Ident) -> TokenStream2 {
+        let mut attrs = TokenStream2::new();
+        if self.inline {
+            attrs.extend(quote!(#[inline(always)]));
+        }
+        if self.doc {
+            let doc = format!(
+                "Delegated to the target's implementation of `{}`.",
+                trait_ident
+            );
+            attrs.extend(quote!(#[doc = #doc]));
+        }
+        attrs
+    }
+
+    /// `#[cfg(..)]` attribute for the generated item, if the impl is conditional
+    pub fn cfg_attr(&self) -> TokenStream2 {
+        match &self.cfg {
+            Some(cfg) => quote!(#[cfg(#cfg)]),
+            None => TokenStream2::new(),
+        }
+    }
+
+    /// `unsafe` keyword for the generated impl, required when delegating an `unsafe trait`
+    pub fn unsafety(&self) -> TokenStream2 {
+        if self.unsafe_impl {
+            quote!(unsafe)
+        } else {
+            TokenStream2::new()
+        }
+    }
+
+    /// `const` keyword after `impl`, required when delegating a `const trait` usable in constant expressions (nightly only)
+    pub fn constness(&self) -> TokenStream2 {
+        if self.const_impl {
+            quote!(const)
+        } else {
+            TokenStream2::new()
+        }
+    }
+
+    /// Bounds of the automatic where clause on the target, the trait followed by those of the `auto_bounds` value
+    pub fn auto_bound(&self, trait_path_full: &syn::Path) -> TokenStream2 {
+        let extra = self.auto_bounds.iter();
+        quote!(#trait_path_full #(+ #extra)*)
+    }
+
+    /// Name the trait was registered under by `#[delegatable_trait]`, used to find its helper macro
+    pub fn registered_ident<'a>(&'a self, trait_ident: &'a Ident) -> &'a Ident {
+        self.registered_as.as_ref().unwrap_or(trait_ident)
+    }
+
+    /// Which methods of the trait are delegated, passed to the registered macro as `[exclude(..)]` or `[include(..)]`,
+    /// prefixed by the `receivers` filter when only methods with certain receivers are delegated
+    /// and by `only_required` when methods with a default implementation are not delegated
+    pub fn method_filter(&self) -> TokenStream2 {
+        let mut prefix = TokenStream2::new();
+        if self.only_required {
+            prefix.extend(quote!(only_required));
+        }
+        if let Some(receivers) = &self.receivers {
+            prefix.extend(register::receivers_filter(receivers));
+        }
+        match &self.included {
+            Some(included) => quote!(#prefix include(#(#included)*)),
+            None => {
+                let excluded = &self.excluded;
+                quote!(#prefix exclude(#(#excluded)*))
+            }
+        }
+    }
+
+    fn add_key_list(&mut self, key: Ident, items: ParseStream<'_>) -> Result<()> {
+        match &*key.to_string() {
+            "exclude" | "include" if !self.excluded.is_empty() || self.included.is_some() => {
+                return error!(
+                    key.span(),
+                    "\"exclude\" and \"include\" can only be specified once per delegate attribute"
+                )
+            }
+            "exclude" => {
+                let excluded = Punctuated::<Ident, Comma>::parse_terminated(items)?;
+                self.excluded.extend(excluded);
+            }
+            "include" => {
+                let included = Punctuated::<Ident, Comma>::parse_terminated(items)?;
+                self.included = Some(included.into_iter().collect());
+            }
+            "where" => {
+                let where_clause_val =
+                    Punctuated::<WherePredicate, Comma>::parse_terminated(items)?;
+                self.where_clauses.extend(where_clause_val);
+            }
+            other => self
+                .target
+                .try_list(other, items)
+                .unwrap_or_else(|| error!(key.span(), "invalid key for a delegate attribute"))?,
+        }
+        Ok(())
+    }
 }
 
 pub(super) fn delegate_attr_as_trait_and_iter<T: DelegateTarget>(
@@ -62,9 +277,23 @@ pub(super) fn delegate_attr_as_trait_and_iter<T: DelegateTarget>(
     while !items.is_empty() {
         let _: Token![,] = items.parse()?;
         let key = items.call(Ident::parse_any)?;
-        let _: Token![=] = items.parse()?;
-        let val = items.parse()?;
-        delegate_args.add_key_value(key, val)?;
+        if items.is_empty() || items.peek(Token![,]) {
+            delegate_args.add_flag(key)?;
+        } else if items.peek(syn::token::Paren) {
+            let list;
+            syn::parenthesized!(list in items);
+            delegate_args.add_key_list(key, &list)?;
+        } else {
+            let _: Token![=] = items.parse()?;
+            // Integers are accepted without quotes for tuple indices (eg. `target = 1`)
+            let val = if items.peek(LitInt) {
+                let index: LitInt = items.parse()?;
+                LitStr::new(index.base10_digits(), index.span())
+            } else {
+                items.parse()?
+            };
+            delegate_args.add_key_value(key, val)?;
+        }
     }
     Ok((path, delegate_args))
 }
@@ -91,7 +320,6 @@ pub(super) fn delegate_macro<I>(
     let mut delegate_attributes = attrs
         .into_iter()
         .filter(|attr| attr.path.is_ident("delegate"))
-        .map(|attr| attr.tokens)
         .peekable();
     if delegate_attributes.peek().is_none() {
         return error!(
@@ -100,24 +328,176 @@ pub(super) fn delegate_macro<I>(
         ).unwrap_or_else(Error::into_compile_error);
     }
 
-    let iter = delegate_attributes
-        .into_iter()
-        .map(|attr| delegate_single(input, attr));
+    let iter = delegate_attributes.map(|attr| {
+        if attr.tokens.is_empty() {
+            return error!(attr.span(), "{}", MISSING_TRAIT_ERROR);
+        }
+        let single_attrs = split_delegate_traits.parse2(attr.tokens)?;
+        let iter = single_attrs.into_iter().map(|attr| {
+            let span = attr.span();
+            delegate_single(input, attr).map(|res| respan_impls(res, span))
+        });
+        process_results(iter, |iter| iter.flatten().collect::<TokenStream2>())
+    });
     let res = process_results(iter, |iter| iter.flatten().collect());
     res.unwrap_or_else(Error::into_compile_error)
 }
 
-pub(super) fn trait_info(trait_path_full: &syn::Path) -> Result<(&Ident, impl ToTokens + '_)> {
+/// Gives the `impl` (and `unsafe`) keyword and body of the generated impls the span of the `#[delegate]` attribute,
+/// so errors about the impl as a whole (eg. missing items) and IDEs point to the attribute requesting it.
+/// Other blocks (the modules and constants wrapping impls) are searched for impls as well
+fn respan_impls(tokens: TokenStream2, span: proc_macro2::Span) -> TokenStream2 {
+    let mut in_impl_header = false;
+    tokens
+        .into_iter()
+        .map(|tt| match tt {
+            TokenTree::Ident(mut ident) if ident == "impl" => {
+                in_impl_header = true;
+                ident.set_span(span);
+                TokenTree::Ident(ident)
+            }
+            TokenTree::Ident(mut ident) if ident == "unsafe" => {
+                ident.set_span(span);
+                TokenTree::Ident(ident)
+            }
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                let mut res = if in_impl_header {
+                    Group::new(Delimiter::Brace, group.stream())
+                } else {
+                    Group::new(Delimiter::Brace, respan_impls(group.stream(), span))
+                };
+                res.set_span(if in_impl_header { span } else { group.span() });
+                in_impl_header = false;
+                TokenTree::Group(res)
+            }
+            tt => tt,
+        })
+        .collect()
+}
+
+const MISSING_TRAIT_ERROR: &str = "expected a trait name, e.g. #[delegate(MyTrait)]";
+
+/// Splits an attribute delegating several traits (eg. `#[delegate(Shout, Sing, target = "foo")]`)
+/// into one attribute per trait, each with all the keys and flags
+fn split_delegate_traits(input: ParseStream<'_>) -> Result<Vec<TokenStream2>> {
+    let items;
+    let paren = syn::parenthesized!(items in input);
+    if items.is_empty() {
+        return error!(paren.span, "{}", MISSING_TRAIT_ERROR);
+    }
+    let mut paths: Vec<syn::Path> = vec![items.parse()?];
+    while is_next_trait_path(&items) {
+        let _: Token![,] = items.parse()?;
+        paths.push(items.parse()?);
+    }
+    let rest: TokenStream2 = items.parse()?;
+    let split = paths.into_iter().map(|path| {
+        let mut group = Group::new(Delimiter::Parenthesis, quote!(#path #rest));
+        group.set_span(paren.span);
+        group.into_token_stream()
+    });
+    Ok(split.collect())
+}
+
+/// Checks if the next item is another trait rather than a key or flag,
+/// bare identifiers starting with a lowercase letter are treated as flags (eg. `inline`)
+fn is_next_trait_path(items: ParseStream<'_>) -> bool {
+    let fork = items.fork();
+    if fork.parse::<Token![,]>().is_err() {
+        return false;
+    }
+    match fork.parse::<syn::Path>() {
+        Ok(path) if fork.is_empty() || fork.peek(Token![,]) => match path.get_ident() {
+            Some(ident) => !ident.to_string().starts_with(|c: char| c.is_lowercase()),
+            None => true,
+        },
+        _ => false,
+    }
+}
+
+fn is_assoc_binding(arg: &GenericArgument) -> bool {
+    matches!(
+        arg,
+        GenericArgument::Binding(_) | GenericArgument::Constraint(_)
+    )
+}
+
+pub(super) fn trait_info(trait_path_full: &syn::Path) -> Result<(&Ident, TokenStream2)> {
     let trait_segment = trait_path_full.segments.last().unwrap();
     let trait_ident: &Ident = &trait_segment.ident;
     let trait_generics = match &trait_segment.arguments {
-        PathArguments::None => None,
-        PathArguments::AngleBracketed(seg) => Some(super::util::TailingPunctuated(&seg.args)),
+        PathArguments::None => TokenStream2::new(),
+        PathArguments::AngleBracketed(seg) => {
+            let args: Punctuated<&GenericArgument, Comma> = seg
+                .args
+                .iter()
+                .filter(|arg| !is_assoc_binding(arg))
+                .collect();
+            super::util::TailingPunctuated(&args).into_token_stream()
+        }
         _ => return error!(trait_path_full.span(), "cannot delegate to Fn* traits"),
     };
     Ok((trait_ident, trait_generics))
 }
 
+/// Removes associated type bindings (eg. `Error = MyErr`) from the trait path so it can be used in an impl header,
+/// they are still checked through the automatic where clause which uses the full path
+pub(super) fn trait_impl_path(trait_path_full: &syn::Path) -> syn::Path {
+    let mut path = trait_path_full.clone();
+    if let Some(PathArguments::AngleBracketed(seg)) =
+        path.segments.last_mut().map(|seg| &mut seg.arguments)
+    {
+        seg.args = std::mem::take(&mut seg.args)
+            .into_iter()
+            .filter(|arg| !is_assoc_binding(arg))
+            .collect();
+    }
+    path
+}
+
+/// The registered macro refers to the trait by its bare name, so a trait given by a longer path
+/// (eg. `my_mod::Trait`) or under a different name than it was registered with has to be imported
+/// as that name where the impl is generated.
+/// `from_child_module` adjusts a relative path for use inside a module generated next to the implementer.
+pub(super) fn trait_import(
+    trait_path: &syn::Path,
+    registered_ident: &Ident,
+    from_child_module: bool,
+) -> Option<TokenStream2> {
+    let last = &trait_path.segments.last().unwrap().ident;
+    if trait_path.segments.len() < 2 && last == registered_ident {
+        return None;
+    }
+    let mut path = trait_path.clone();
+    path.segments.last_mut().unwrap().arguments = PathArguments::None;
+    if from_child_module && path.leading_colon.is_none() {
+        let first = &path.segments[0].ident;
+        if first == "self" {
+            path.segments[0].ident = Ident::new("super", first.span());
+        } else if first == "super" {
+            path.segments.insert(0, parse_quote!(super));
+        }
+    }
+    Some(quote!(#[allow(unused_imports)] use #path as #registered_ident;))
+}
+
+/// Places a generated impl in an anonymous const together with the trait import if one is needed
+pub(super) fn with_trait_import(
+    trait_path: &syn::Path,
+    registered_ident: &Ident,
+    item: TokenStream2,
+) -> TokenStream2 {
+    match trait_import(trait_path, registered_ident, false) {
+        Some(import) => quote! {
+            const _: () = {
+                #import
+                #item
+            };
+        },
+        None => item,
+    }
+}
+
 pub(super) fn merge_impl_generics(
     impl_generics: ImplGenerics,
     added_generics: Vec<GenericParam>,
@@ -129,20 +509,27 @@ pub(super) fn merge_impl_generics(
         let generics: Generics = parse_quote!(#tokens);
         generics.params
     };
-    // Make sure all lifetimes come first
-    impl_generics.into_iter().merge_by(added_generics, |x, _| {
-        matches!(x, GenericParam::Lifetime(_))
-    })
+    let mut params: Vec<_> = impl_generics.into_iter().chain(added_generics).collect();
+    params.sort_by_key(generic_param_rank);
+    params.into_iter()
 }
 
 pub(super) fn merge_generics<'a>(
     impl_generics: &'a Punctuated<GenericParam, Token![,]>,
     added_generics: &'a [GenericParam],
 ) -> impl Iterator<Item = &'a GenericParam> {
-    // Make sure all lifetimes come first
-    impl_generics.iter().merge_by(added_generics, |&x, _| {
-        matches!(x, GenericParam::Lifetime(_))
-    })
+    let mut params: Vec<_> = impl_generics.iter().chain(added_generics).collect();
+    params.sort_by_key(|param| generic_param_rank(param));
+    params.into_iter()
+}
+
+// Lifetimes have to come first and, before Rust 1.59, const parameters have to come last
+fn generic_param_rank(param: &GenericParam) -> u8 {
+    match param {
+        GenericParam::Lifetime(_) => 0,
+        GenericParam::Type(_) => 1,
+        GenericParam::Const(_) => 2,
+    }
 }
 
 pub(super) fn build_where_clause(
@@ -150,18 +537,36 @@ pub(super) fn build_where_clause(
     where_clause: Option<&WhereClause>,
 ) -> WhereClause {
     // Merges the where clause based on the type generics with all the where clauses specified
-    // via "where" macro attributes.
-    explicit_where_clauses.extend(where_clause.into_iter().flat_map(|n| n.predicates.clone()));
-    WhereClause {
+    // via "where" macro attributes (in the order they were given), dropping repeated predicates.
+    let mut clause = WhereClause {
         where_token: Default::default(),
-        predicates: explicit_where_clauses,
+        predicates: Punctuated::new(),
+    };
+    let type_predicates = where_clause.into_iter().flat_map(|n| n.predicates.clone());
+    for predicate in std::mem::take(&mut explicit_where_clauses)
+        .into_iter()
+        .chain(type_predicates)
+    {
+        push_where_predicate(&mut clause, predicate);
+    }
+    clause
+}
+
+/// Adds a predicate to the where clause unless it is already there
+pub(super) fn push_where_predicate(clause: &mut WhereClause, predicate: WherePredicate) {
+    if !clause
+        .predicates
+        .iter()
+        .any(|existing| *existing == predicate)
+    {
+        clause.predicates.push(predicate);
     }
 }
 
 pub(super) fn add_auto_where_clause(
     clause: &mut WhereClause,
-    trait_path_full: &syn::Path,
+    auto_bound: &TokenStream2,
     ty: &syn::Type,
 ) {
-    clause.predicates.push(parse_quote!(#ty : #trait_path_full))
+    push_where_predicate(clause, parse_quote!(#ty : #auto_bound))
 }
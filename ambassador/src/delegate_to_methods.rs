@@ -191,7 +191,7 @@ impl DelegateTarget {
 // Checks that:
 //   - all the items in an impl are methods
 //   - all the methods are _empty_ (no body, just the signature)
-fn check_for_method_impls_and_extras(impl_items: &[syn::ImplItem]) -> Result<()> {
+pub(crate) fn check_for_method_impls_and_extras(impl_items: &[syn::ImplItem]) -> Result<()> {
     let iter = impl_items.iter().filter_map(|i| {
         // We're looking for *only* empty methods (no block).
         if let syn::ImplItem::Method(m) = i {
@@ -256,10 +256,13 @@ pub fn delegate_macro(input: TokenStream, keep_impl_block: bool) -> TokenStream
     // Parse the input tokens into a syntax tree
     let mut input = parse_macro_input!(input as ItemImpl);
     let attrs = std::mem::take(&mut input.attrs);
-    assert!(
-        attrs.iter().all(|attr| attr.path.is_ident("delegate")),
-        "All attributes must be \"delegate\""
-    );
+    let other_attrs = attrs
+        .iter()
+        .filter(|attr| !attr.path.is_ident("delegate"))
+        .map(|attr| syn::Error::new(attr.span(), "All attributes must be \"delegate\""));
+    if let Err(err) = fold_errors(Ok(()), other_attrs) {
+        return err.into_compile_error().into();
+    }
     let keep_info = if keep_impl_block {
         Keep(input.to_token_stream())
     } else {
@@ -302,7 +305,16 @@ fn delegate_single_attr(
     let span = delegate_attr.span();
     let (trait_path_full, args) = DelegateArgs::from_tokens(delegate_attr)?;
     let (trait_ident, trait_generics_p) = delegate_shared::trait_info(&trait_path_full)?;
-    let macro_name: Ident = macro_name(trait_ident);
+    let trait_path = delegate_shared::trait_impl_path(&trait_path_full);
+    let auto_bound = args.auto_bound(&trait_path_full);
+    let method_attrs = args.method_attrs(trait_ident);
+    let method_filter = args.method_filter();
+    let from_inner = &args.from_inner;
+    let cfg_attr = args.cfg_attr();
+    let unsafety = args.unsafety();
+    let constness = args.constness();
+    let registered_ident = args.registered_ident(trait_ident).clone();
+    let macro_name: Ident = macro_name(&registered_ident);
 
     let impl_generics = delegate_shared::merge_generics(&implementer.impl_generics, &args.generics);
     let implementer_ty = &implementer.ty;
@@ -313,11 +325,14 @@ fn delegate_single_attr(
     let owned_ident = args.target.owned_id.into_iter();
     let ref_ident = args.target.ref_id.into_iter();
     let ref_mut_ident = args.target.ref_mut_id.into_iter();
-    add_auto_where_clause(&mut where_clause, &trait_path_full, delegate_ty);
+    if !args.inhibit_automatic_where_clause {
+        add_auto_where_clause(&mut where_clause, &auto_bound, delegate_ty);
+    }
     let res = quote! {
-        impl <#(#impl_generics,)*> #trait_path_full for #implementer_ty #where_clause {
-            #macro_name!{body_struct(<#trait_generics_p>, #delegate_ty, (#(#owned_ident())*), (#(#ref_ident())*), (#(#ref_mut_ident())*))}
+        #unsafety impl <#(#impl_generics,)*> #constness #trait_path for #implementer_ty #where_clause {
+            #macro_name!{body_struct([#method_filter], [#method_attrs], [#from_inner], <#trait_generics_p>, #delegate_ty, (#(#owned_ident())*), (#(#ref_ident())*), (#(#ref_mut_ident())*))}
         }
     };
-    Ok(res)
+    let res = delegate_shared::with_trait_import(&trait_path, &registered_ident, res);
+    Ok(quote!(#cfg_attr #res))
 }
@@ -1,56 +1,248 @@
 use crate::delegate_shared::{self, add_auto_where_clause};
 use crate::register::{macro_name, match_name};
 use crate::util::{error, process_results, try_option};
+use itertools::Itertools;
 use proc_macro::TokenStream;
-use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
-use quote::quote;
+use proc_macro2::{Group, Ident, Span, TokenStream as TokenStream2, TokenTree};
+use quote::{quote, ToTokens};
 use std::default::Default;
+use syn::parse::ParseStream;
 use syn::spanned::Spanned;
-use syn::{parse_macro_input, parse_quote, DeriveInput, Generics, LitStr, Result, WherePredicate};
+use syn::{parse_macro_input, parse_quote, DeriveInput, Generics, LitStr, Result, Token};
 
 #[derive(Debug)]
 struct DelegateImplementer {
     generics: Generics,
     ty: Ident,
     info: DelegateImplementerInfo,
+    /// Field marked with a bare `#[delegate]`, used when an attribute on the type has no target
+    default_target: Option<syn::Member>,
+}
+
+#[derive(Debug)]
+struct EnumVariant {
+    ident: Ident,
+    span: Span,
+    fields: Vec<(syn::Member, syn::Type)>,
+    /// Expression the methods evaluate to for a variant marked with `#[delegate(skip)]`
+    skip: Option<syn::Expr>,
 }
 
 #[derive(Debug)]
 enum DelegateImplementerInfo {
     Enum {
-        variant_idents: Vec<Ident>,
-        first_type: syn::Type,
-        other_types: Vec<syn::Type>,
+        variants: Vec<EnumVariant>,
     },
     SingleFieldStruct {
-        field_ident: syn::Member,
-        field_type: syn::Type,
+        field: Box<(syn::Member, syn::Type)>,
     },
     MultiFieldStruct {
         fields: Vec<(syn::Member, syn::Type)>,
     },
 }
 
-fn try_info_from_data(span: Span, data: syn::Data) -> Result<DelegateImplementerInfo> {
+impl DelegateImplementerInfo {
+    fn struct_fields(&self) -> Option<&[(syn::Member, syn::Type)]> {
+        match self {
+            DelegateImplementerInfo::Enum { .. } => None,
+            DelegateImplementerInfo::SingleFieldStruct { field } => {
+                Some(std::slice::from_ref(&**field))
+            }
+            DelegateImplementerInfo::MultiFieldStruct { fields } => Some(fields),
+        }
+    }
+}
+
+fn members_and_types(fields: syn::Fields) -> Vec<(syn::Member, syn::Type)> {
+    fields
+        .into_iter()
+        .enumerate()
+        .map(|(i, field)| match field.ident {
+            Some(id) => (syn::Member::Named(id), field.ty),
+            None => (syn::Member::Unnamed(i.into()), field.ty),
+        })
+        .collect()
+}
+
+/// Select the field of an enum variant to delegate to, either the only one or the one named by `target`
+fn get_variant_field<'a>(
+    variant: &'a EnumVariant,
+    target: Option<&syn::Member>,
+) -> Result<&'a (syn::Member, syn::Type)> {
+    let name = &variant.ident;
+    match (target, &variant.fields[..]) {
+        (Some(target), fields) => match fields.iter().find(|(member, _)| member == target) {
+            Some(field) => Ok(field),
+            None if is_tuple_index(target, fields) => error!(
+                variant.span,
+                "enum variant `{}` has only {} field(s), too few for the field `{}` specified as \"target\"",
+                name,
+                fields.len(),
+                target.to_token_stream()
+            ),
+            None => error!(
+                variant.span,
+                "enum variant `{}` has no field `{}` specified as \"target\"",
+                name,
+                target.to_token_stream()
+            ),
+        },
+        (None, []) => error!(
+            variant.span,
+            "enum variant `{}` has no fields, mark it with #[delegate(skip)] if it doesn't delegate",
+            name
+        ),
+        (None, [field]) => Ok(field),
+        (None, _) => error!(variant.span, "enum variant `{}` has multiple fields", name),
+    }
+}
+
+/// Field given for `variant` in the `target(Variant = "field", ..)` list, which has to cover every variant
+fn variant_target<'a>(
+    variant: &EnumVariant,
+    targets: &'a [(Ident, syn::Member)],
+) -> Result<&'a syn::Member> {
+    match targets.iter().find(|(name, _)| *name == variant.ident) {
+        Some((_, member)) => Ok(member),
+        None => error!(
+            variant.span,
+            "enum variant `{}` is missing from the \"target\" list on #[delegate] attribute",
+            variant.ident
+        ),
+    }
+}
+
+/// Places the impl generated for a struct in the module named by the `module` value if there is one,
+/// otherwise it is returned as is (in an anonymous const if the trait needs to be imported)
+fn wrap_struct_impl(
+    module: Option<&Ident>,
+    trait_path: &syn::Path,
+    registered_ident: &Ident,
+    item: TokenStream2,
+) -> TokenStream2 {
+    match module {
+        Some(module) => {
+            let trait_import = delegate_shared::trait_import(trait_path, registered_ident, true);
+            quote! {
+                #[allow(non_snake_case)]
+                mod #module {
+                    use super::*;
+                    #trait_import
+                    #item
+                }
+            }
+        }
+        None => delegate_shared::with_trait_import(trait_path, registered_ident, item),
+    }
+}
+
+/// Checks if `target` is an index into a tuple variant (as opposed to a variant with named fields)
+fn is_tuple_index(target: &syn::Member, fields: &[(syn::Member, syn::Type)]) -> bool {
+    matches!(target, syn::Member::Unnamed(_))
+        && fields
+            .iter()
+            .all(|(member, _)| matches!(member, syn::Member::Unnamed(_)))
+}
+
+/// Finds the struct field marked with a bare `#[delegate]` attribute
+fn marked_field(data: &syn::Data) -> Result<Option<syn::Member>> {
+    let is_marker = |attr: &&syn::Attribute| attr.path.is_ident("delegate");
+    let fields = match data {
+        syn::Data::Struct(struct_data) => &struct_data.fields,
+        syn::Data::Enum(enum_data) => {
+            let fields = enum_data
+                .variants
+                .iter()
+                .flat_map(|variant| &variant.fields);
+            return match fields.flat_map(|field| &field.attrs).find(is_marker) {
+                Some(attr) => error!(
+                    attr.span(),
+                    "#[delegate] attribute on a field can only be used for structs"
+                ),
+                None => Ok(None),
+            };
+        }
+        syn::Data::Union(_) => return Ok(None),
+    };
+    let mut marked = None;
+    for (i, field) in fields.iter().enumerate() {
+        let attr = match field.attrs.iter().find(is_marker) {
+            Some(attr) => attr,
+            None => continue,
+        };
+        if !attr.tokens.is_empty() {
+            return error!(
+                attr.tokens.span(),
+                "#[delegate] attribute on a field takes no arguments, it only marks the default target"
+            );
+        }
+        if marked.is_some() {
+            return error!(
+                attr.span(),
+                "#[delegate] attribute can only be used on one field"
+            );
+        }
+        marked = Some(match &field.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(i.into()),
+        });
+    }
+    Ok(marked)
+}
+
+/// Parses a `#[delegate(skip)]` or `#[delegate(skip = "fallback")]` attribute on an enum variant
+fn variant_skip(enum_ident: &Ident, variant: &syn::Variant) -> Result<Option<syn::Expr>> {
+    let attr = match variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("delegate"))
+    {
+        Some(attr) => attr,
+        None => return Ok(None),
+    };
+    if attr.tokens.is_empty() {
+        return error!(
+            attr.span(),
+            "#[delegate] attribute on an enum variant needs the \"skip\" flag, eg. #[delegate(skip)]"
+        );
+    }
+    let parser = |input: ParseStream<'_>| {
+        let key: Ident = input.parse()?;
+        if key != "skip" {
+            return error!(
+                key.span(),
+                "invalid key for #[delegate] attribute on an enum variant, expected \"skip\""
+            );
+        }
+        if input.parse::<Option<Token![=]>>()?.is_some() {
+            let lit: LitStr = input.parse()?;
+            return lit.parse();
+        }
+        let message = LitStr::new(
+            &format!(
+                "enum variant `{}::{}` is skipped by #[delegate(skip)]",
+                enum_ident, variant.ident
+            ),
+            variant.ident.span(),
+        );
+        Ok(parse_quote!(::core::unreachable!(#message)))
+    };
+    attr.parse_args_with(parser).map(Some)
+}
+
+fn try_info_from_data(ident: &Ident, data: syn::Data) -> Result<DelegateImplementerInfo> {
     let res = match data {
         syn::Data::Enum(enum_data) => {
             let iter = enum_data.variants.into_iter().map(|n| {
-                let span = n.span();
-                let mut it = n.fields.into_iter();
-                match it.next() {
-                    None => error!(span, "enum variant has no fields"),
-                    Some(_) if it.count() != 0 => error!(span, "enum variant has multiple fields"),
-                    Some(f) => Ok((n.ident, f.ty)),
-                }
+                Ok(EnumVariant {
+                    skip: variant_skip(ident, &n)?,
+                    span: n.span(),
+                    ident: n.ident,
+                    fields: members_and_types(n.fields),
+                })
             });
-            let (variant_idents, mut variant_types): (Vec<_>, Vec<_>) =
-                process_results(iter, |iter| iter.unzip())?;
-            let first_type = variant_types.pop().expect("enum has no variants");
-            DelegateImplementerInfo::Enum {
-                variant_idents,
-                first_type,
-                other_types: variant_types,
-            }
+            let variants = process_results(iter, |iter| iter.collect())?;
+            DelegateImplementerInfo::Enum { variants }
         }
         syn::Data::Struct(struct_data) => match struct_data.fields.len() {
             1 => {
@@ -60,28 +252,18 @@ fn try_info_from_data(span: Span, data: syn::Data) -> Result<DelegateImplementer
                     None => syn::Member::Unnamed(0.into()),
                 };
                 DelegateImplementerInfo::SingleFieldStruct {
-                    field_ident,
-                    field_type: field.ty,
+                    field: Box::new((field_ident, field.ty)),
                 }
             }
-            _ => {
-                let fields = struct_data
-                    .fields
-                    .into_iter()
-                    .enumerate()
-                    .map(|(i, field)| match field.ident {
-                        Some(id) => (syn::Member::Named(id), field.ty),
-                        None => (syn::Member::Unnamed(i.into()), field.ty),
-                    })
-                    .collect();
-                DelegateImplementerInfo::MultiFieldStruct { fields }
-            }
+            _ => DelegateImplementerInfo::MultiFieldStruct {
+                fields: members_and_types(struct_data.fields),
+            },
         },
-        _ => {
+        syn::Data::Union(union_data) => {
             return error!(
-                span,
+                union_data.union_token.span,
                 "ambassador currently only supports #[derive(Delegate)] for: \n\
-             - single-field enums\n\
+             - enums\n\
              - (tuple) structs"
             )
         }
@@ -89,71 +271,564 @@ fn try_info_from_data(span: Span, data: syn::Data) -> Result<DelegateImplementer
     Ok(res)
 }
 
-enum DelegateTarget {
-    Field(syn::Member),
-    TrgNone,
+enum Target {
     TrgSelf,
+    Field(syn::Member),
+    Deref(Option<syn::Member>),
+    /// A field of a field, eg. `target = "wrapper.inner"`
+    Nested(syn::Member, Vec<syn::Member>),
+    /// An element of a field, eg. `target = "0[0]"`
+    Index(syn::Member, proc_macro2::Literal),
+    Expr(Box<syn::Expr>),
 }
 
-impl Default for DelegateTarget {
-    fn default() -> Self {
-        DelegateTarget::TrgNone
+impl Target {
+    fn parse(lit: &LitStr, allow_self: bool) -> Result<Self> {
+        if lit.value() == "self" {
+            return if allow_self {
+                Ok(Target::TrgSelf)
+            } else {
+                error!(lit.span(), "\"self\" can only be used as \"target\" value")
+            };
+        }
+        if let Some(deref_target) = lit.value().strip_prefix('*') {
+            let deref_target = deref_target.trim();
+            if deref_target.is_empty() {
+                return Ok(Target::Deref(None));
+            }
+            if let Ok(member) = LitStr::new(deref_target, lit.span()).parse() {
+                return Ok(Target::Deref(Some(member)));
+            }
+        }
+        let value = lit.value();
+        if let Some((field, index)) = value.strip_suffix(']').and_then(|v| v.split_once('[')) {
+            let field = LitStr::new(field.trim(), lit.span()).parse();
+            let index = LitStr::new(index.trim(), lit.span()).parse::<syn::LitInt>();
+            if let (Ok(field), Ok(index)) = (field, index) {
+                let mut index = proc_macro2::Literal::usize_suffixed(index.base10_parse()?);
+                index.set_span(lit.span());
+                return Ok(Target::Index(field, index));
+            }
+        }
+        let members = value
+            .split('.')
+            .map(|member| LitStr::new(member.trim(), lit.span()).parse())
+            .collect::<Result<Vec<syn::Member>>>();
+        if let (Ok(mut members), true) = (members, value.contains('.')) {
+            let first = members.remove(0);
+            return Ok(Target::Nested(first, members));
+        }
+        match (lit.parse(), lit.parse::<syn::Expr>()) {
+            (Ok(member), _) => Ok(Target::Field(member)),
+            (Err(_), Ok(expr)) if mentions_self(expr.to_token_stream()) => {
+                Ok(Target::Expr(Box::new(expr)))
+            }
+            (Err(err), _) => Err(err),
+        }
     }
 }
 
+#[derive(Default)]
+struct DelegateTarget {
+    target: Option<Target>,
+    owned: Option<Target>,
+    ref_r: Option<Target>,
+    ref_mut: Option<Target>,
+    module: Option<Ident>,
+    /// Emit the enum impl in an anonymous const instead of a module, from the `no_module` flag
+    no_module: bool,
+    per_variant: bool,
+    /// Delegate to the value inside the target field, from the `unwrap`, `interior_mut`, `lock` or `rwlock` flag
+    field_access: Option<FieldAccess>,
+    /// Message of the panic when a lock is poisoned, from the `poisoned` value
+    poisoned: Option<LitStr>,
+    /// Delegate to the implementer's own `Deref` target, from the `deref` flag
+    deref: bool,
+    /// Build `Self` from the target and the other fields' defaults, from the `default_fields` flag
+    default_fields: bool,
+    /// Call the target's methods through the trait even without the automatic where clause, from the `hygienic` flag
+    hygienic: bool,
+    /// Methods delegated to another field than `target`, from `route(method = "field", ..)`
+    routes: Vec<(Ident, syn::Member)>,
+    /// Type of the struct field to delegate to, from the `target_type` value
+    target_type: Option<syn::Type>,
+    /// Field of each enum variant to delegate to, from `target(Variant = "field", ..)`
+    variant_targets: Vec<(Ident, syn::Member)>,
+}
+
 impl delegate_shared::DelegateTarget for DelegateTarget {
     fn try_update(&mut self, key: &str, lit: LitStr) -> Option<Result<()>> {
+        if key == "module" {
+            self.module = Some(try_option!(lit.parse()));
+            return Some(Ok(()));
+        }
+        if key == "poisoned" {
+            self.poisoned = Some(lit);
+            return Some(Ok(()));
+        }
+        if key == "target_type" {
+            if self.target_type.is_some() {
+                try_option!(error!(
+                    lit.span(),
+                    "\"target_type\" value for delegate attribute can only be specified once"
+                ));
+            }
+            self.target_type = Some(try_option!(lit.parse()));
+            return Some(Ok(()));
+        }
+        let slot = match key {
+            "target" => &mut self.target,
+            "target_owned" => &mut self.owned,
+            "target_ref" => &mut self.ref_r,
+            "target_mut" => &mut self.ref_mut,
+            _ => return None,
+        };
+        if slot.is_some() {
+            try_option!(error!(
+                lit.span(),
+                "\"{}\" value for delegate attribute can only be specified once", key
+            ));
+        }
+        *slot = Some(try_option!(Target::parse(&lit, key == "target")));
+        Some(Ok(()))
+    }
+
+    fn try_flag(&mut self, key: &str) -> Option<Result<()>> {
         match key {
-            "target" => {
-                if !matches!(self, DelegateTarget::TrgNone) {
-                    try_option!(error!(
-                        lit.span(),
-                        "\"target\" value for delegate attribute can only be specified once"
-                    ));
-                }
-                *self = if lit.value() == "self" {
-                    DelegateTarget::TrgSelf
-                } else {
-                    let target_val = try_option!(lit.parse());
-                    DelegateTarget::Field(target_val)
-                };
+            "per_variant" => {
+                self.per_variant = true;
+                Some(Ok(()))
+            }
+            "no_module" => {
+                self.no_module = true;
+                Some(Ok(()))
+            }
+            "unwrap" => Some(self.set_field_access(FieldAccess::Unwrap)),
+            "interior_mut" => Some(self.set_field_access(FieldAccess::InteriorMut)),
+            "lock" => Some(self.set_field_access(FieldAccess::Lock)),
+            "rwlock" => Some(self.set_field_access(FieldAccess::RwLock)),
+            "deref" => {
+                self.deref = true;
+                Some(Ok(()))
+            }
+            "default_fields" => {
+                self.default_fields = true;
+                Some(Ok(()))
+            }
+            "hygienic" => {
+                self.hygienic = true;
                 Some(Ok(()))
             }
             _ => None,
         }
     }
+
+    fn try_list(&mut self, key: &str, items: ParseStream<'_>) -> Option<Result<()>> {
+        let list = match key {
+            "route" => &mut self.routes,
+            "target" => &mut self.variant_targets,
+            _ => return None,
+        };
+        let parse_entry = |input: ParseStream<'_>| -> Result<(Ident, syn::Member)> {
+            let name = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let field: LitStr = input.parse()?;
+            Ok((name, field.parse()?))
+        };
+        let entries = try_option!(items.parse_terminated::<_, Token![,]>(parse_entry));
+        list.extend(entries);
+        Some(Ok(()))
+    }
+}
+
+impl DelegateTarget {
+    fn has_receiver_targets(&self) -> bool {
+        self.owned.is_some() || self.ref_r.is_some() || self.ref_mut.is_some()
+    }
+
+    fn set_field_access(&mut self, access: FieldAccess) -> Result<()> {
+        match self.field_access {
+            Some(other) if other != access => error!(
+                Span::call_site(),
+                "\"{}\" and \"{}\" flags on #[delegate] attribute can not be used together",
+                other.flag(),
+                access.flag()
+            ),
+            _ => {
+                self.field_access = Some(access);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Wrapper around the value a struct field delegates to
+#[derive(Clone, Copy, PartialEq)]
+enum FieldAccess {
+    /// `Option<T>`, panicking on `None`
+    Unwrap,
+    /// `RefCell<T>`, borrowed for the duration of the call
+    InteriorMut,
+    /// `Mutex<T>`, locked for the duration of the call
+    Lock,
+    /// `RwLock<T>`, locked for reading or writing depending on the receiver
+    RwLock,
+}
+
+impl FieldAccess {
+    fn flag(self) -> &'static str {
+        match self {
+            FieldAccess::Unwrap => "unwrap",
+            FieldAccess::InteriorMut => "interior_mut",
+            FieldAccess::Lock => "lock",
+            FieldAccess::RwLock => "rwlock",
+        }
+    }
+
+    fn wrapper(self) -> &'static str {
+        match self {
+            FieldAccess::Unwrap => "Option",
+            FieldAccess::InteriorMut => "RefCell",
+            FieldAccess::Lock => "Mutex",
+            FieldAccess::RwLock => "RwLock",
+        }
+    }
+
+    fn wrapper_description(self) -> &'static str {
+        match self {
+            FieldAccess::Unwrap => "an `Option`",
+            FieldAccess::InteriorMut => "a `RefCell`",
+            FieldAccess::Lock => "a `Mutex`",
+            FieldAccess::RwLock => "an `RwLock`",
+        }
+    }
+
+    /// Expressions reaching the inner value for methods taking `self`, `&self` and `&mut self`,
+    /// poisoned locks panic with the `poisoned` message if one is given
+    fn exprs(
+        self,
+        this: &Ident,
+        field_ident: &syn::Member,
+        implementer_ident: &Ident,
+        poisoned: Option<&LitStr>,
+    ) -> (TokenStream2, TokenStream2, TokenStream2) {
+        let unpoison = match poisoned {
+            Some(message) => quote!(expect(#message)),
+            None => quote!(unwrap()),
+        };
+        match self {
+            FieldAccess::Unwrap => {
+                let message = format!(
+                    "`{}` field of `{}` is `None`",
+                    field_ident.to_token_stream(),
+                    implementer_ident
+                );
+                (
+                    quote!(#this.#field_ident.expect(#message)),
+                    quote!((*#this.#field_ident.as_ref().expect(#message))),
+                    quote!((*#this.#field_ident.as_mut().expect(#message))),
+                )
+            }
+            FieldAccess::InteriorMut => (
+                quote!(#this.#field_ident.into_inner()),
+                quote!((*#this.#field_ident.borrow())),
+                quote!((*#this.#field_ident.borrow_mut())),
+            ),
+            FieldAccess::Lock => (
+                quote!(#this.#field_ident.into_inner().#unpoison),
+                quote!((*#this.#field_ident.lock().#unpoison)),
+                quote!((*#this.#field_ident.lock().#unpoison)),
+            ),
+            FieldAccess::RwLock => (
+                quote!(#this.#field_ident.into_inner().#unpoison),
+                quote!((*#this.#field_ident.read().#unpoison)),
+                quote!((*#this.#field_ident.write().#unpoison)),
+            ),
+        }
+    }
 }
 
 type DelegateArgs = delegate_shared::DelegateArgs<DelegateTarget>;
 
+fn mentions_self(tokens: TokenStream2) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Group(g) => mentions_self(g.stream()),
+        TokenTree::Ident(id) => id == "self",
+        _ => false,
+    })
+}
+
+// `self` can't be passed into the registered macro (it would be unhygienic there),
+// so every `self` in the expression is replaced with `this`, which the macro binds to `self`
+fn replace_self(tokens: TokenStream2, this: &Ident) -> TokenStream2 {
+    tokens
+        .into_iter()
+        .map(|tt| match tt {
+            TokenTree::Group(g) => {
+                let mut res = Group::new(g.delimiter(), replace_self(g.stream(), this));
+                res.set_span(g.span());
+                TokenTree::Group(res)
+            }
+            TokenTree::Ident(id) if id == "self" => TokenTree::Ident(this.clone()),
+            tt => tt,
+        })
+        .collect()
+}
+
+/// Gives all of `tokens` the span of `span`, so an error about them points there
+fn respan(tokens: TokenStream2, span: Span) -> TokenStream2 {
+    tokens
+        .into_iter()
+        .map(|mut tt| {
+            if let TokenTree::Group(g) = &tt {
+                tt = TokenTree::Group(Group::new(g.delimiter(), respan(g.stream(), span)));
+            }
+            tt.set_span(span);
+            tt
+        })
+        .collect()
+}
+
+/// Converts an expression `target` into the tokens passed to `body_struct_expr`,
+/// adding parentheses where a trailing method call would otherwise bind too tightly.
+fn expr_target_tokens(expr: &syn::Expr, this: &Ident) -> TokenStream2 {
+    use syn::Expr::*;
+    let tokens = replace_self(expr.to_token_stream(), this);
+    match expr {
+        Call(_) | Field(_) | Index(_) | MethodCall(_) | Paren(_) | Path(_) => tokens,
+        _ => quote!((#tokens)),
+    }
+}
+
+/// Accesses a field of a field (eg. `ambassador_self.wrapper.inner`), only the first field can be checked here
+fn nested_field_tokens(
+    first: &syn::Member,
+    rest: &[syn::Member],
+    fields: &[(syn::Member, syn::Type)],
+    implementer_ident: &Ident,
+    this: &Ident,
+) -> Result<TokenStream2> {
+    let first = &get_field(first, fields, implementer_ident)?.0;
+    Ok(quote!(#this.#first #(.#rest)*))
+}
+
+/// Lists the fields of a struct for error messages, eg. "`foo`, `bar`"
+fn available_fields(field_idents: &[(syn::Member, syn::Type)]) -> String {
+    field_idents
+        .iter()
+        .map(|(member, _)| format!("`{}`", member.to_token_stream()))
+        .join(", ")
+}
+
 /// Select the correct field_ident based on the `target`.
 pub fn get_field<'a>(
     target: &syn::Member,
     field_idents: &'a [(syn::Member, syn::Type)],
+    implementer_ident: &Ident,
 ) -> Result<&'a (syn::Member, syn::Type)> {
     let field = field_idents.iter().find(|n| n.0 == *target);
     match field {
         Some(field) => Ok(field),
+        None if is_tuple_index(target, field_idents) => error!(
+            target.span(),
+            "field index {} out of range (struct `{}` has {} field(s))",
+            target.to_token_stream(),
+            implementer_ident,
+            field_idents.len()
+        ),
         None => error!(
             target.span(),
-            "Unknown field specified as \"target\" value in #[delegate] attribute"
+            "Unknown field specified as \"target\" value in #[delegate] attribute, available fields of `{}` are: {}",
+            implementer_ident,
+            available_fields(field_idents)
+        ),
+    }
+}
+
+/// Selects the only field with the type given as `target_type` value, types are compared as written
+fn get_field_by_type<'a>(
+    target_type: &syn::Type,
+    field_idents: &'a [(syn::Member, syn::Type)],
+    implementer_ident: &Ident,
+) -> Result<&'a syn::Member> {
+    let type_name = target_type.to_token_stream().to_string();
+    let matching: Vec<_> = field_idents
+        .iter()
+        .filter(|(_, field_type)| field_type.to_token_stream().to_string() == type_name)
+        .collect();
+    match &matching[..] {
+        [(member, _)] => Ok(member),
+        [] => error!(
+            target_type.span(),
+            "No field of type `{}` specified as \"target_type\" value in #[delegate] attribute, available fields of `{}` are: {}",
+            type_name,
+            implementer_ident,
+            available_fields(field_idents)
+        ),
+        _ => error!(
+            target_type.span(),
+            "Multiple fields of type `{}` specified as \"target_type\" value in #[delegate] attribute, use a \"target\" value to select one of: {}",
+            type_name,
+            matching
+                .iter()
+                .map(|(member, _)| format!("`{}`", member.to_token_stream()))
+                .join(", ")
         ),
     }
 }
 
+/// `from_inner` used with the `default_fields` flag, the other fields are set to their own default.
+/// Their `default()` calls are spanned to the field type so a field that isn't `Default` is pointed out
+fn default_from_inner(target: &syn::Member, fields: &[(syn::Member, syn::Type)]) -> TokenStream2 {
+    let inner = Ident::new("ambassador_inner", Span::call_site());
+    let values = fields.iter().map(|(member, ty)| {
+        if member == target {
+            quote!(#member: #inner)
+        } else {
+            let default =
+                quote::quote_spanned!(ty.span()=> <#ty as ::core::default::Default>::default());
+            quote!(#member: #default)
+        }
+    });
+    quote!(|#inner| Self { #(#values),* })
+}
+
+/// Select the field to dereference for `target = "*"` (single field structs) or `target = "*foo"`.
+fn get_deref_field<'a>(
+    target: &Option<syn::Member>,
+    fields: &'a [(syn::Member, syn::Type)],
+    implementer_ident: &Ident,
+    span: Span,
+) -> Result<&'a (syn::Member, syn::Type)> {
+    match (target, fields) {
+        (Some(target), _) => get_field(target, fields, implementer_ident),
+        (None, [field]) => Ok(field),
+        (None, _) => error!(
+            span,
+            "target = \"*\" can only be used for structs with a single field, use \"*field\" to select one"
+        ),
+    }
+}
+
+/// The referenced type of a `&T` or `&mut T` field is used as is, which keeps the automatic where clause readable
+fn deref_target_type(field_type: &syn::Type) -> syn::Type {
+    match field_type {
+        syn::Type::Reference(reference) => (*reference.elem).clone(),
+        field_type => parse_quote!(<#field_type as ::core::ops::Deref>::Target),
+    }
+}
+
+/// Returns `T` for a field type like `Option<T>` when `name` is `"Option"`
+fn single_type_arg<'a>(field_type: &'a syn::Type, name: &str) -> Option<&'a syn::Type> {
+    let path = match field_type {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => path,
+        _ => return None,
+    };
+    let last = path.segments.last()?;
+    match &last.arguments {
+        syn::PathArguments::AngleBracketed(args) if last.ident == name => {
+            match args.args.iter().collect::<Vec<_>>()[..] {
+                [syn::GenericArgument::Type(arg)] => Some(arg),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the `dyn Trait` type behind a `Box<dyn Trait>`, `&dyn Trait` or `&mut dyn Trait` field,
+/// these don't implement the trait themselves so methods are called on the trait object directly.
+fn trait_object_type<'a>(field_type: &'a syn::Type, trait_ident: &Ident) -> Option<&'a syn::Type> {
+    let pointee = match field_type {
+        syn::Type::Reference(reference) => &*reference.elem,
+        field_type => single_type_arg(field_type, "Box")?,
+    };
+    let bounds = match pointee {
+        syn::Type::TraitObject(trait_object) => &trait_object.bounds,
+        _ => return None,
+    };
+    let names_trait = bounds.iter().any(|bound| match bound {
+        syn::TypeParamBound::Trait(bound) => {
+            matches!(bound.path.segments.last(), Some(segment) if &segment.ident == trait_ident)
+        }
+        _ => false,
+    });
+    if names_trait {
+        Some(pointee)
+    } else {
+        None
+    }
+}
+
+/// Builds the arguments to `body_struct_expr` when `target_owned`, `target_ref` or `target_mut` is used,
+/// falling back to `target` for the receivers that weren't specified.
+fn receiver_targets_body(
+    implementer: &DelegateImplementer,
+    target: &DelegateTarget,
+    span: Span,
+) -> Result<TokenStream2> {
+    let fields = match implementer.info.struct_fields() {
+        Some(fields) => fields,
+        None => {
+            return error!(
+                span,
+                "\"target\" values on #[delegate] attribute can not be specified for enums"
+            )
+        }
+    };
+    let this = Ident::new("ambassador_self", Span::call_site());
+    let to_tokens = |trg: &Option<Target>| match trg.as_ref().or(target.target.as_ref()) {
+        None => Ok(TokenStream2::new()),
+        Some(Target::TrgSelf) => error!(
+            span,
+            "target = \"self\" can not be combined with other \"target\" values"
+        ),
+        Some(Target::Field(field)) => {
+            let field = &get_field(field, fields, &implementer.ty)?.0;
+            Ok(quote!(#this.#field))
+        }
+        Some(Target::Deref(field)) => {
+            let field = &get_deref_field(field, fields, &implementer.ty, span)?.0;
+            Ok(quote!((*#this.#field)))
+        }
+        Some(Target::Nested(first, rest)) => {
+            nested_field_tokens(first, rest, fields, &implementer.ty, &this)
+        }
+        Some(Target::Index(field, index)) => {
+            let field = &get_field(field, fields, &implementer.ty)?.0;
+            Ok(quote!(#this.#field[#index]))
+        }
+        Some(Target::Expr(expr)) => Ok(expr_target_tokens(expr, &this)),
+    };
+    let owned = to_tokens(&target.owned)?;
+    let ref_r = to_tokens(&target.ref_r)?;
+    let ref_mut = to_tokens(&target.ref_mut)?;
+    Ok(quote!(_, #this, (#owned), (#ref_r), (#ref_mut)))
+}
+
 pub fn delegate_macro(input: TokenStream) -> TokenStream {
     // Parse the input tokens into a syntax tree
     let input = parse_macro_input!(input as DeriveInput);
-    let info = match try_info_from_data(input.span(), input.data) {
+    expand_derive(input).into()
+}
+
+/// Expands `#[derive(Delegate)]` without going through `proc_macro`, so it can also be used in tests
+pub(crate) fn expand_derive(input: DeriveInput) -> TokenStream2 {
+    let default_target = match marked_field(&input.data) {
+        Ok(default_target) => default_target,
+        Err(err) => return err.into_compile_error(),
+    };
+    let info = match try_info_from_data(&input.ident, input.data) {
         Ok(info) => info,
-        Err(err) => return err.into_compile_error().into(),
+        Err(err) => return err.into_compile_error(),
     };
     let implementer = DelegateImplementer {
         info,
+        default_target,
         generics: input.generics,
         ty: input.ident,
     };
-    delegate_shared::delegate_macro(&implementer, input.attrs, delegate_single_attr).into()
+    delegate_shared::delegate_macro(&implementer, input.attrs, delegate_single_attr)
 }
 
 fn delegate_single_attr(
@@ -161,85 +836,546 @@ fn delegate_single_attr(
     delegate_attr: TokenStream2,
 ) -> Result<TokenStream2> {
     let span = delegate_attr.span();
-    let (trait_path_full, args) = DelegateArgs::from_tokens(delegate_attr)?;
+    let (trait_path_full, mut args) = DelegateArgs::from_tokens(delegate_attr)?;
     let (trait_ident, trait_generics_p) = delegate_shared::trait_info(&trait_path_full)?;
-    let macro_name: Ident = macro_name(trait_ident);
+    let trait_path = delegate_shared::trait_impl_path(&trait_path_full);
+    let auto_bound = args.auto_bound(&trait_path_full);
+    if let Some(target_type) = args.target.target_type.take() {
+        let fields = match implementer.info.struct_fields() {
+            Some(fields) => fields,
+            None => {
+                return error!(
+                target_type.span(),
+                "\"target_type\" value on #[delegate] attribute can only be specified for structs"
+            )
+            }
+        };
+        if args.target.target.is_some() {
+            return error!(
+                target_type.span(),
+                "\"target\" and \"target_type\" values on #[delegate] attribute can not be used together"
+            );
+        }
+        let member = get_field_by_type(&target_type, fields, &implementer.ty)?;
+        args.target.target = Some(Target::Field(member.clone()));
+    }
+    if let Some(default_target) = &implementer.default_target {
+        let target = &args.target;
+        if target.target.is_none() && !target.deref && !target.has_receiver_targets() {
+            args.target.target = Some(Target::Field(default_target.clone()));
+        }
+    }
+    // Routed methods are left out of the main target
+    let routed: Vec<_> = args
+        .target
+        .routes
+        .iter()
+        .map(|(method, _)| method.clone())
+        .collect();
+    match &mut args.included {
+        Some(included) => included.retain(|method| !routed.contains(method)),
+        None => args.excluded.extend(routed.iter().cloned()),
+    }
+    let is_enum = matches!(implementer.info, DelegateImplementerInfo::Enum { .. });
+    if args.target.default_fields {
+        if args.from_inner.is_some() {
+            return error!(
+                span,
+                "\"default_fields\" flag and \"from_inner\" value on #[delegate] attribute can not be used together"
+            );
+        }
+        if is_enum
+            || !matches!(args.target.target, None | Some(Target::Field(_)))
+            || args.target.deref
+            || args.target.has_receiver_targets()
+            || args.target.field_access.is_some()
+        {
+            return error!(
+                span,
+                "\"default_fields\" flag on #[delegate] attribute can only be used with a struct field as target"
+            );
+        }
+    }
+    let expr_target =
+        matches!(args.target.target, Some(Target::Expr(_))) || args.target.has_receiver_targets();
+    if args.target.hygienic && expr_target {
+        return error!(
+            span,
+            "\"hygienic\" flag on #[delegate] attribute can not be used with expression or receiver targets"
+        );
+    }
+    // Struct targets are called through the trait so inherent methods of the same name can't be picked instead,
+    // expressions may evaluate to a reference to the target and enums choose the call mode of each variant.
+    // Without the automatic where clause the target may only have compatible methods (eg. inherent or through `Deref`),
+    // unless the `hygienic` flag requires the trait's
+    let qualify_calls = args.target.hygienic || !args.inhibit_automatic_where_clause;
+    let call_mode = if !expr_target && !is_enum && qualify_calls {
+        quote!(hygienic)
+    } else {
+        TokenStream2::new()
+    };
+    let method_attrs = args.method_attrs(trait_ident);
+    let method_filter = args.method_filter();
+    let method_filter = quote!(#call_mode #method_filter);
+    let from_inner = &args.from_inner;
+    let cfg_attr = args.cfg_attr();
+    let unsafety = args.unsafety();
+    let constness = args.constness();
+    let registered_ident = args.registered_ident(trait_ident).clone();
+    let macro_name: Ident = macro_name(&registered_ident);
 
     let generics = &implementer.generics;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let mut where_clause = delegate_shared::build_where_clause(args.where_clauses, where_clause);
-    let impl_generics = delegate_shared::merge_impl_generics(impl_generics, args.generics);
+    let impl_generics: Vec<_> =
+        delegate_shared::merge_impl_generics(impl_generics, args.generics).collect();
     let implementer_ident = &implementer.ty;
-    use {DelegateImplementerInfo::*, DelegateTarget::*};
-    let res = match (&args.target, &implementer.info) {
-        (TrgSelf, _) => quote! {
-            impl <#(#impl_generics,)*> #trait_path_full for #implementer_ident #ty_generics #where_clause {
-                #macro_name!{body_self(<#trait_generics_p>)}
+    if !is_enum {
+        if args.target.per_variant {
+            return error!(
+                span,
+                "\"per_variant\" flag on #[delegate] attribute can only be specified for enums"
+            );
+        }
+        if args.target.no_module {
+            return error!(
+                span,
+                "\"no_module\" flag on #[delegate] attribute can only be specified for enums"
+            );
+        }
+        if let Some((variant, _)) = args.target.variant_targets.first() {
+            return error!(
+                variant.span(),
+                "\"target\" list on #[delegate] attribute can only be specified for enums, use a \"target\" value for structs"
+            );
+        }
+    }
+    if let (Some(_), Some((variant, _))) =
+        (&args.target.target, args.target.variant_targets.first())
+    {
+        return error!(
+            variant.span(),
+            "\"target\" value and \"target\" list on #[delegate] attribute can not be used together"
+        );
+    }
+    if let (Some(module), true) = (&args.target.module, args.target.no_module) {
+        return error!(
+            module.span(),
+            "\"module\" value and \"no_module\" flag on #[delegate] attribute can not be used together"
+        );
+    }
+    if let Some(from_inner) = from_inner {
+        if is_enum || matches!(args.target.target, Some(Target::TrgSelf)) {
+            return error!(
+                from_inner.span(),
+                "\"from_inner\" value on #[delegate] attribute can only be specified for struct fields and expressions"
+            );
+        }
+    }
+    let field_target = matches!(args.target.target, None | Some(Target::Field(_)));
+    if let Some(poisoned) = &args.target.poisoned {
+        if !matches!(
+            args.target.field_access,
+            Some(FieldAccess::Lock | FieldAccess::RwLock)
+        ) {
+            return error!(
+                poisoned.span(),
+                "\"poisoned\" value on #[delegate] attribute can only be specified with the \"lock\" or \"rwlock\" flag"
+            );
+        }
+    }
+    if let Some(access) = args.target.field_access {
+        if is_enum || !field_target || args.target.has_receiver_targets() {
+            return error!(
+                span,
+                "\"{}\" flag on #[delegate] attribute can only be specified for struct fields",
+                access.flag()
+            );
+        }
+    }
+    let mut route_items = TokenStream2::new();
+    if !routed.is_empty() {
+        let fields = match implementer.info.struct_fields() {
+            Some(fields) => fields,
+            None => {
+                return error!(
+                    span,
+                    "\"route\" on #[delegate] attribute can only be specified for structs"
+                )
             }
-        },
-        (Field(field), Enum {..}) => return error!(
-            field.span(),
+        };
+        // Methods routed to the same field share one invocation
+        let mut groups: Vec<(&syn::Member, Vec<&Ident>)> = Vec::new();
+        for (method, member) in &args.target.routes {
+            match groups.iter_mut().find(|(other, _)| *other == member) {
+                Some((_, methods)) => methods.push(method),
+                None => groups.push((member, vec![method])),
+            }
+        }
+        for (member, methods) in groups {
+            let (field_ident, field_type) = get_field(member, fields, implementer_ident)?;
+            if !args.inhibit_automatic_where_clause {
+                add_auto_where_clause(&mut where_clause, &auto_bound, field_type);
+            }
+            route_items.extend(quote! {
+                #macro_name!{body_struct([#call_mode route(#(#methods)*)], [#method_attrs], [#from_inner], <#trait_generics_p>, #field_type, (#field_ident), (#field_ident), (#field_ident))}
+            });
+        }
+    }
+    // Every delegated impl has the same header, only the invocation of the registered macro differs
+    let delegated_impl = |where_clause: &syn::WhereClause, body: TokenStream2| {
+        quote! {
+            #[automatically_derived]
+            #unsafety impl <#(#impl_generics,)*> #constness #trait_path for #implementer_ident #ty_generics #where_clause {
+                #body
+                #route_items
+            }
+        }
+    };
+    use {DelegateImplementerInfo::*, Target::*};
+    if args.target.deref {
+        if args.target.target.is_some()
+            || args.target.has_receiver_targets()
+            || args.target.field_access.is_some()
+        {
+            return error!(
+                span,
+                "\"deref\" flag on #[delegate] attribute can not be combined with \"target\" values or other field flags"
+            );
+        }
+        // Goes through the implementer's `Deref` impl, method call syntax on `*self` would find the delegated impl itself
+        let target_type: syn::Type = parse_quote!(<Self as ::core::ops::Deref>::Target);
+        if !args.inhibit_automatic_where_clause {
+            add_auto_where_clause(&mut where_clause, &auto_bound, &target_type);
+        }
+        let this = Ident::new("ambassador_self", Span::call_site());
+        let owned = quote!((*::core::ops::Deref::deref(&#this)));
+        let ref_r = quote!((*::core::ops::Deref::deref(#this)));
+        let ref_mut = quote!((*::core::ops::DerefMut::deref_mut(#this)));
+        let res = delegated_impl(
+            &where_clause,
+            quote!(#macro_name!{body_struct_expr([#method_filter], [#method_attrs], [#from_inner], <#trait_generics_p>, #target_type, #this, (#owned), (#ref_r), (#ref_mut))}),
+        );
+        let res = wrap_struct_impl(
+            args.target.module.as_ref(),
+            &trait_path,
+            &registered_ident,
+            res,
+        );
+        return Ok(quote!(#cfg_attr #res));
+    }
+    if args.target.has_receiver_targets() {
+        let body = receiver_targets_body(implementer, &args.target, span)?;
+        let res = delegated_impl(
+            &where_clause,
+            quote!(#macro_name!{body_struct_expr([#method_filter], [#method_attrs], [#from_inner], <#trait_generics_p>, #body)}),
+        );
+        let res = wrap_struct_impl(
+            args.target.module.as_ref(),
+            &trait_path,
+            &registered_ident,
+            res,
+        );
+        return Ok(quote!(#cfg_attr #res));
+    }
+    let res = match (&args.target.target, &implementer.info) {
+        (Some(TrgSelf), _) => delegated_impl(
+            &where_clause,
+            quote!(#macro_name!{body_self([#method_filter], [#method_attrs], <#trait_generics_p>)}),
+        ),
+        (Some(Deref(_)), Enum {..}) => return error!(
+            span,
             "\"target\" value on #[delegate] attribute can not be specified for enums"
         ),
-        (TrgNone, Enum {variant_idents, first_type, other_types}) => {
-            if !args.inhibit_automatic_where_clause {
-                add_auto_where_clause(&mut where_clause, &trait_path_full, first_type);
+        (Some(Nested(first, _)), Enum {..}) | (Some(Index(first, _)), Enum {..}) => return error!(
+            first.span(),
+            "\"target\" value on #[delegate] attribute can not be specified for enums"
+        ),
+        (Some(Expr(expr)), Enum {..}) => return error!(
+            expr.span(),
+            "\"target\" value on #[delegate] attribute can not be specified for enums"
+        ),
+        (target @ (None | Some(Field(_))), Enum {variants}) => {
+            let target = match target {
+                Some(Field(field)) => Some(field),
+                _ => None,
+            };
+            let variant_targets = &args.target.variant_targets;
+            if let Some((name, _)) = variant_targets
+                .iter()
+                .find(|(name, _)| variants.iter().all(|variant| variant.ident != *name))
+            {
+                return error!(
+                    name.span(),
+                    "enum `{}` has no variant `{}` specified in the \"target\" list",
+                    implementer_ident,
+                    name
+                );
             }
-            let match_name = match_name(trait_ident);
-            where_clause
-                .predicates
-                .extend(other_types.iter().map::<WherePredicate, _>(
-                    |arg| parse_quote!(#arg : #match_name<#trait_generics_p #first_type>),
-                ));
-            let mod_name = quote::format_ident!(
-                "ambassador_module_{}_for_{}",
-                trait_ident,
-                implementer_ident
+            if let Some((name, _)) = variant_targets.iter().find(|(name, _)| {
+                variants
+                    .iter()
+                    .any(|variant| variant.ident == *name && variant.skip.is_some())
+            }) {
+                return error!(
+                    name.span(),
+                    "enum variant `{}` is skipped by #[delegate(skip)] and can't be in the \"target\" list",
+                    name
+                );
+            }
+            // Skipped variants don't delegate, their arms evaluate the fallback instead
+            let (skipped, variants): (Vec<_>, Vec<_>) =
+                variants.iter().partition(|variant| variant.skip.is_some());
+            let skipped_arms = if skipped.is_empty() {
+                TokenStream2::new()
+            } else {
+                let skipped_idents = skipped.iter().map(|variant| &variant.ident);
+                let fallbacks = skipped.iter().map(|variant| &variant.skip);
+                quote!(, (#(#implementer_ident::#skipped_idents => #fallbacks),*))
+            };
+            let iter = variants.iter().map(|variant| {
+                let target = if variant_targets.is_empty() {
+                    target
+                } else {
+                    Some(variant_target(variant, variant_targets)?)
+                };
+                let (member, ty) = get_variant_field(variant, target)?;
+                Ok((&variant.ident, member, ty))
+            });
+            let (variant_idents, members, other_types): (Vec<_>, Vec<_>, Vec<_>) =
+                process_results(iter, |iter| iter.multiunzip())?;
+            // Variants are called through the trait like struct targets, except trait objects behind a pointer
+            let call_modes = other_types.iter().map(|ty| {
+                if !qualify_calls || trait_object_type(ty, trait_ident).is_some() {
+                    TokenStream2::new()
+                } else {
+                    quote!([hygienic])
+                }
+            });
+            let variants = quote!(#(#implementer_ident::#variant_idents {#members} #call_modes),*);
+            // Variants holding a trait object of the trait dispatch to it directly and need no bounds
+            let mut other_types: Vec<&syn::Type> = other_types
+                .into_iter()
+                .map(|ty| trait_object_type(ty, trait_ident).unwrap_or(ty))
+                .collect();
+            let is_object = |ty: &syn::Type| matches!(ty, syn::Type::TraitObject(_));
+            // The other variants are matched against a sized type if there is one
+            let first_type = match other_types.iter().rposition(|ty| !is_object(ty)) {
+                Some(index) => Some(other_types.remove(index)),
+                None => other_types.pop(),
+            };
+            let first_type = match first_type {
+                Some(first_type) if args.target.per_variant => {
+                    if !args.inhibit_automatic_where_clause {
+                        for ty in other_types.iter().copied().chain(Some(first_type)) {
+                            if !is_object(ty) {
+                                add_auto_where_clause(&mut where_clause, &auto_bound, ty);
+                            }
+                        }
+                    }
+                    first_type.to_token_stream()
+                }
+                Some(first_type) => {
+                    // Variants of a generic trait may implement other instantiations only, bounding each of them
+                    // on the delegated one first makes the error point at the variant that doesn't implement it
+                    if !trait_generics_p.is_empty() && !args.inhibit_automatic_where_clause {
+                        for ty in other_types.iter().copied().chain(Some(first_type)) {
+                            if !is_object(ty) {
+                                let predicate = respan(quote!(#ty : #trait_path), ty.span());
+                                delegate_shared::push_where_predicate(
+                                    &mut where_clause,
+                                    parse_quote!(#predicate),
+                                );
+                            }
+                        }
+                    }
+                    if !args.inhibit_automatic_where_clause && !is_object(first_type) {
+                        add_auto_where_clause(&mut where_clause, &auto_bound, first_type);
+                    }
+                    let match_name = match_name(&registered_ident);
+                    for arg in &other_types {
+                        if is_object(arg) || is_object(first_type) {
+                            continue;
+                        }
+                        let extra_bounds = args.auto_bounds.iter();
+                        delegate_shared::push_where_predicate(
+                            &mut where_clause,
+                            parse_quote!(#arg : #match_name<#trait_generics_p #first_type> #(+ #extra_bounds)*),
+                        );
+                    }
+                    first_type.to_token_stream()
+                }
+                // Enums without variants are uninhabited so there is nothing to delegate to
+                None => quote!(_),
+            };
+            let body = delegated_impl(
+                &where_clause,
+                quote!(#macro_name!{body_enum([#method_filter], [#method_attrs], <#trait_generics_p>, #first_type, (#(#other_types),*), (#variants) #skipped_arms)}),
             );
-            quote! {
+            let body = quote!(#macro_name!{use_assoc_ty_bounds} #body);
+            if args.target.no_module {
+                // An anonymous const still scopes the `Match` helper trait, but resolves names like the enum
+                let trait_import = delegate_shared::trait_import(&trait_path, &registered_ident, false);
+                return Ok(quote! {
+                    #cfg_attr
+                    const _: () = {
+                        #trait_import
+                        #body
+                    };
+                });
+            }
+            let mod_name = args.target.module.clone().unwrap_or_else(|| {
+                quote::format_ident!("ambassador_module_{}_for_{}", trait_ident, implementer_ident)
+            });
+            let trait_import = delegate_shared::trait_import(&trait_path, &registered_ident, true);
+            // The module already gives the impl its own scope, so it is returned as is
+            return Ok(quote! {
+                #cfg_attr
                 #[allow(non_snake_case)]
                 mod #mod_name {
                     use super::*;
-                    #macro_name!{use_assoc_ty_bounds}
-                    impl <#(#impl_generics,)*> #trait_path_full for #implementer_ident #ty_generics #where_clause {
-                        #macro_name!{body_enum(<#trait_generics_p>, #first_type, (#(#other_types),*), (#(#implementer_ident::#variant_idents),*))}
-                    }
+                    #trait_import
+                    #body
                 }
-            }
+            });
         }
-        (Field(field), SingleFieldStruct {..}) => return error!(
-            field.span(),
-            "\"target\" value on #[delegate] attribute can not be specified for structs with a single field"
-        ),
-        (TrgNone, SingleFieldStruct {field_ident, field_type}) => {
+        (Some(Expr(expr)), SingleFieldStruct {..}) | (Some(Expr(expr)), MultiFieldStruct {..}) => {
+            let this = Ident::new("ambassador_self", Span::call_site());
+            let expr = expr_target_tokens(expr, &this);
+            delegated_impl(
+                &where_clause,
+                quote!(#macro_name!{body_struct_expr([#method_filter], [#method_attrs], [#from_inner], <#trait_generics_p>, _, #this, (#expr), (#expr), (#expr))}),
+            )
+        }
+        (Some(Nested(first, rest)), info) => {
+            let fields = info.struct_fields().expect("enums are handled above");
+            let this = Ident::new("ambassador_self", Span::call_site());
+            let expr = nested_field_tokens(first, rest, fields, implementer_ident, &this)?;
+            delegated_impl(
+                &where_clause,
+                quote!(#macro_name!{body_struct_expr([#method_filter], [#method_attrs], [#from_inner], <#trait_generics_p>, _, #this, (#expr), (#expr), (#expr))}),
+            )
+        }
+        (Some(Index(field, index)), info) => {
+            let fields = info.struct_fields().expect("enums are handled above");
+            let (field_ident, field_type) = get_field(field, fields, implementer_ident)?;
+            let target_type: syn::Type =
+                parse_quote!(<#field_type as ::core::ops::Index<usize>>::Output);
             if !args.inhibit_automatic_where_clause {
-                add_auto_where_clause(&mut where_clause, &trait_path_full, field_type);
+                add_auto_where_clause(&mut where_clause, &auto_bound, &target_type);
             }
-
-            quote! {
-                impl <#(#impl_generics,)*> #trait_path_full for #implementer_ident #ty_generics #where_clause {
-                    #macro_name!{body_struct(<#trait_generics_p>, #field_type, #field_ident)}
-                }
+            let this = Ident::new("ambassador_self", Span::call_site());
+            // Elements can't be moved out of the field, so methods taking `self` only work for `Copy` elements
+            let owned = quote!((#this.#field_ident[#index]));
+            let ref_r = quote!((*::core::ops::Index::index(&#this.#field_ident, #index)));
+            let ref_mut =
+                quote!((*::core::ops::IndexMut::index_mut(&mut #this.#field_ident, #index)));
+            delegated_impl(
+                &where_clause,
+                quote!(#macro_name!{body_struct_expr([#method_filter], [#method_attrs], [#from_inner], <#trait_generics_p>, #target_type, #this, (#owned), (#ref_r), (#ref_mut))}),
+            )
+        }
+        (Some(Deref(field)), info) => {
+            let fields = info.struct_fields().expect("enums are handled above");
+            let (field_ident, field_type) =
+                get_deref_field(field, fields, implementer_ident, span)?;
+            let target_type = deref_target_type(field_type);
+            if !args.inhibit_automatic_where_clause {
+                add_auto_where_clause(&mut where_clause, &auto_bound, &target_type);
             }
+            let this = Ident::new("ambassador_self", Span::call_site());
+            // Only moving out of a `Box` is possible, which needs the `*` operator rather than a call to `Deref::deref`
+            let owned = quote!((*#this.#field_ident));
+            let ref_r = quote!((*::core::ops::Deref::deref(&#this.#field_ident)));
+            let ref_mut = quote!((*::core::ops::DerefMut::deref_mut(&mut #this.#field_ident)));
+            delegated_impl(
+                &where_clause,
+                quote!(#macro_name!{body_struct_expr([#method_filter], [#method_attrs], [#from_inner], <#trait_generics_p>, #target_type, #this, (#owned), (#ref_r), (#ref_mut))}),
+            )
         }
-        (TrgNone, MultiFieldStruct {..}) => return error!(
+        // Without a main target every method without a default implementation needs a route
+        (None, MultiFieldStruct {..}) if !routed.is_empty() => {
+            delegated_impl(
+                &where_clause,
+                quote!(#macro_name!{check_routed(#(#routed)*)}),
+            )
+        }
+        (None, info @ MultiFieldStruct {..}) => return error!(
             span,
-            "\"target\" value on #[delegate] attribute has to be specified for structs with multiple fields"
+            "\"target\" value on #[delegate] attribute has to be specified for structs with multiple fields, available fields of `{}` are: {}",
+            implementer_ident,
+            available_fields(info.struct_fields().expect("enums are handled above"))
         ),
-        (Field(field), MultiFieldStruct {fields}) => {
-            let field = get_field(field, fields)?;
-            let field_ident = &field.0;
-            let field_type = &field.1;
-            if !args.inhibit_automatic_where_clause {
-                add_auto_where_clause(&mut where_clause, &trait_path_full, field_type);
-            }
-
-            quote! {
-                impl <#(#impl_generics,)*> #trait_path_full for #implementer_ident #ty_generics #where_clause {
-                    #macro_name!{body_struct(<#trait_generics_p>, #field_type, #field_ident)}
+        (target @ (None | Some(Field(_))), info) => {
+            let fields = info.struct_fields().expect("enums are handled above");
+            let (field_ident, field_type) = match target {
+                Some(Field(field)) => get_field(field, fields, implementer_ident)?,
+                _ => &fields[0],
+            };
+            // Trait objects of the trait are called directly and need no bound, references don't implement
+            // the trait themselves so the referenced type is bounded and called
+            let pointee_type = match (trait_object_type(field_type, trait_ident), field_type) {
+                (Some(object_type), _) => Some((object_type, false)),
+                (None, syn::Type::Reference(reference)) if !args.inhibit_automatic_where_clause => {
+                    Some((&*reference.elem, true))
+                }
+                _ => None,
+            };
+            if let Some(access) = args.target.field_access {
+                let inner_type = match single_type_arg(field_type, access.wrapper()) {
+                    Some(inner_type) => inner_type,
+                    None => return error!(
+                        field_type.span(),
+                        "\"{}\" flag on #[delegate] attribute requires the target field to be {}",
+                        access.flag(),
+                        access.wrapper_description()
+                    ),
+                };
+                if !args.inhibit_automatic_where_clause {
+                    add_auto_where_clause(&mut where_clause, &auto_bound, inner_type);
+                }
+                let this = Ident::new("ambassador_self", Span::call_site());
+                let (owned, ref_r, ref_mut) = access.exprs(
+                    &this,
+                    field_ident,
+                    implementer_ident,
+                    args.target.poisoned.as_ref(),
+                );
+                delegated_impl(
+                    &where_clause,
+                    quote!(#macro_name!{body_struct_expr([#method_filter], [#method_attrs], [#from_inner], <#trait_generics_p>, #inner_type, #this, (#owned), (#ref_r), (#ref_mut))}),
+                )
+            } else if let Some((target_type, bounded)) = pointee_type {
+                if bounded {
+                    add_auto_where_clause(&mut where_clause, &auto_bound, target_type);
                 }
+                let this = Ident::new("ambassador_self", Span::call_site());
+                let expr = quote!((*#this.#field_ident));
+                delegated_impl(
+                    &where_clause,
+                    quote!(#macro_name!{body_struct_expr([#method_filter], [#method_attrs], [#from_inner], <#trait_generics_p>, #target_type, #this, (#expr), (#expr), (#expr))}),
+                )
+            } else {
+                if !args.inhibit_automatic_where_clause {
+                    add_auto_where_clause(&mut where_clause, &auto_bound, field_type);
+                }
+                let from_inner = if args.target.default_fields {
+                    Some(default_from_inner(field_ident, fields))
+                } else {
+                    from_inner.as_ref().map(ToTokens::to_token_stream)
+                };
+                delegated_impl(
+                    &where_clause,
+                    quote!(#macro_name!{body_struct([#method_filter], [#method_attrs], [#from_inner], <#trait_generics_p>, #field_type, (#field_ident), (#field_ident), (#field_ident))}),
+                )
             }
         }
     };
-    Ok(res)
+    let res = wrap_struct_impl(
+        args.target.module.as_ref(),
+        &trait_path,
+        &registered_ident,
+        res,
+    );
+    Ok(quote!(#cfg_attr #res))
 }
@@ -103,16 +103,20 @@
 extern crate core;
 extern crate proc_macro;
 
+mod delegate_inherent;
 mod delegate_shared;
 mod delegate_to_methods;
 mod derive;
 mod register;
+#[cfg(test)]
+mod snapshot_tests;
 mod util;
 
 use proc_macro::TokenStream;
 use quote::quote;
+use syn::parse_macro_input;
 
-use crate::register::build_register_trait;
+use crate::register::{build_register_trait, parse_register_attr, without_trait_constness};
 
 /// Delegate the implementation of a trait to a struct field/enum variants by adding `#[derive(Delegate)]` and its associated attribute `#[delegate(Trait)]` to it:
 ///
@@ -137,9 +141,25 @@ use crate::register::build_register_trait;
 /// pub struct WrappedCat(Cat);
 /// ```
 ///
+/// Associated types and constants of the trait are delegated as well (eg. `const NUM_LEGS: usize = <Cat as Legs>::NUM_LEGS;`),
+/// for enums all variants must agree on them.
+///
+/// Methods taking `self` by value move the target field out of `self`, which isn't possible for types implementing `Drop`.
+/// Other arguments of type `Self`, `&Self` or `&mut Self` (eg. the `rhs` of `std::ops::Add`) are delegated to the same field
+/// of the argument when delegating to a struct field, so operator traits can be delegated to a newtype's inner value.
+///
+/// The trait can also be referred to by its path (eg. `#[delegate(animals::Shout)]`) without importing it,
+/// an absolute path (eg. `#[delegate(::std::fmt::Display)]`) is kept as is and isn't affected by local items of the same name.
+/// The helper macro registered by `#[delegatable_trait]` still has to be in scope under the trait's name.
+///
+/// Several traits can be delegated with a single attribute by listing them before any keys, eg. `#[delegate(Shout, Walk, target = "foo")]`.
+/// The keys and flags then apply to each of the traits. Bare lowercase identifiers in the list are read as flags (eg. `inline`),
+/// so a lowercase trait name needs an attribute of its own.
+///
 ///#### `#[delegate(..., target = "foo")]` - `target` key
 ///
-/// For structs with multiple fields, the field that should act as delegation target can be specified via the `target` key:
+/// For structs with multiple fields, the field that should act as delegation target can be specified via the `target` key
+/// (it may also be given for single field structs to be explicit):
 ///
 /// ```
 /// # use ambassador::{Delegate, delegatable_trait};
@@ -182,6 +202,193 @@ use crate::register::build_register_trait;
 /// #[delegate(Shout, target = "1")] // <-------- Delegate implementation of Shout to second field
 /// pub struct WrappedCats(Cat, Cat);
 /// ```
+/// The index may also be written as a plain integer, eg. `target = 1`.
+/// Alternatively the field can be selected by its type with `target_type`, eg. `target_type = "Cat"`,
+/// which requires exactly one field of the struct to have that type as written.
+///
+/// Instead of repeating the `target` for every trait, one field can be marked with a bare `#[delegate]` attribute
+/// (eg. `struct Pet { #[delegate] cat: Cat, age: u32 }`), which is then used by all `#[delegate(...)]` attributes without a target.
+///
+/// A field of a field can be given as a dotted path, eg. `target = "wrapper.inner"` or `target = "1.0"`.
+/// Only the first field is known to the derive, so like expression targets (see below) no automatic where clause is added
+/// and this doesn't work for traits with associated types and constants.
+///
+/// An element of a field that can be indexed by `usize` (eg. a `Vec` or an array) is given as `target = "items[0]"`.
+/// The automatic where clause then bounds the element type (`<Vec<Inner> as Index<usize>>::Output: Trait`),
+/// and methods taking `&mut self` need the field to implement `IndexMut`. Like any indexing the delegated methods panic
+/// if the element isn't there (eg. the `Vec` is empty), and only `Copy` elements can be used by methods taking `self`.
+///
+/// For enums, `target` selects the field of every variant to delegate to,
+/// which allows variants with multiple fields as long as each of them has a field with that name or index.
+///
+/// ```
+/// # use ambassador::{Delegate, delegatable_trait};
+/// # #[delegatable_trait]
+/// # pub trait Shout {
+/// #     fn shout(&self, input: &str) -> String;
+/// # }
+/// # pub struct Cat;
+/// # impl Shout for Cat {
+/// #     fn shout(&self, input: &str) -> String {
+/// #         format!("{} - meow!", input)
+/// #     }
+/// # }
+/// # pub struct Dog;
+/// # impl Shout for Dog {
+/// #     fn shout(&self, input: &str) -> String {
+/// #         format!("{} - wuff!", input)
+/// #     }
+/// # }
+/// #[derive(Delegate)]
+/// #[delegate(Shout, target = "1")] // <-------- Delegate implementation of Shout to the second field of each variant
+/// pub enum NamedAnimals {
+///     Cat(String, Cat),
+///     Dog(String, Dog, u32),
+/// }
+/// # fn main() {}
+/// ```
+///
+/// Variants with different shapes can each pick their own field with a `target` list instead,
+/// eg. `#[delegate(Shout, target(Cat = "1", Dog = "dog"))]`, which has to name every variant of the enum.
+///
+/// Variants that don't delegate, like marker unit variants, can be left out by marking them with `#[delegate(skip)]`.
+/// Calling a delegated method on them is then `unreachable!()`, or evaluates the fallback expression given with
+/// eg. `#[delegate(skip = "panic!(\"no animal\")")]`, which has to fit the return type of every method.
+/// The mark applies to all traits delegated by the enum, and skipped variants aren't named in a `target` list.
+///
+/// #### `#[delegate(..., target = "self.inner()")]` - expression as `target`
+/// The `target` can also be an expression based on `self`, such as a call to an accessor method.
+/// The same expression is used for all receiver types, so it must be usable behind `&self` and `&mut self` as needed by the trait.
+/// Since the type of the expression isn't known, no automatic where clause is added
+/// and this doesn't work for traits with associated types and constants (see [`macro@delegate_to_methods`] for those).
+///
+/// ```
+/// # use ambassador::{Delegate, delegatable_trait};
+/// # #[delegatable_trait]
+/// # pub trait Shout {
+/// #     fn shout(&self, input: &str) -> String;
+/// # }
+/// # pub struct Cat;
+/// #
+/// # impl Shout for Cat {
+/// #     fn shout(&self, input: &str) -> String {
+/// #         format!("{} - meow!", input)
+/// #     }
+/// # }
+///
+/// #[derive(Delegate)]
+/// #[delegate(Shout, target = "self.inner()")] // <-------- Delegate implementation of Shout to the result of .inner()
+/// pub struct BoxedCat(Box<Cat>);
+///
+/// impl BoxedCat {
+///     fn inner(&self) -> &Cat {
+///         &self.0
+///     }
+/// }
+/// ```
+///
+/// #### `#[delegate(..., target = "*")]` - delegating through `Deref`
+/// Newtypes over smart pointers like `Box<T>` or `Rc<T>` can delegate to the pointee with `target = "*"`,
+/// or `target = "*foo"` to select a field of a struct with multiple fields.
+/// The automatic where clause then requires `<Field as Deref>::Target` to implement the trait,
+/// and methods taking `&mut self` additionally need the field to implement `DerefMut`.
+/// The field is dereferenced with explicit `Deref::deref` and `DerefMut::deref_mut` calls, so any smart pointer works
+/// and inherent methods of the pointer type are never called in place of the trait's (nor are the target's own).
+/// A field holding a reference (eg. `&'a Inner`) delegates to the referenced value even without `target = "*"`,
+/// the automatic where clause then bounds `Inner` directly. To delegate to a reference that implements the trait itself,
+/// turn the automatic where clause off (see below) and give the bound with the `where` key (eg. `where = "&'a Inner: Shout"`).
+///
+/// ```
+/// # use ambassador::{Delegate, delegatable_trait};
+/// # #[delegatable_trait]
+/// # pub trait Shout {
+/// #     fn shout(&self, input: &str) -> String;
+/// # }
+/// #[derive(Delegate)]
+/// #[delegate(Shout, target = "*")] // <-------- Delegate implementation of Shout to the boxed trait object
+/// pub struct BoxedAnimal(Box<dyn Shout>);
+/// ```
+///
+/// Fields of type `Box<dyn Trait>`, `&dyn Trait` and `&mut dyn Trait` of the delegated trait are also recognized without `target = "*"`:
+/// their methods are called on the trait object directly and no `Box<dyn Trait>: Trait` bound is added.
+/// The same goes for enum variants holding such a trait object, which can be mixed with variants of other types.
+///
+/// #### `#[delegate(..., deref)]` - delegating through the implementer's own `Deref`
+/// With the `deref` flag the methods are called on the implementer's `Deref` target (and `DerefMut` for methods taking `&mut self`),
+/// without naming a field. This is useful when the target is computed or the field shouldn't be named.
+/// The automatic where clause bounds `<Self as Deref>::Target`, and the flag can't be combined with `target` values.
+///
+/// #### `#[delegate(..., unwrap)]` - delegating to an `Option` field
+/// With the `unwrap` flag the target field is an `Option` and the methods are called on the value inside it,
+/// which panics if the field is `None`. The automatic where clause bounds the type inside the `Option`.
+///
+/// ```
+/// # use ambassador::{Delegate, delegatable_trait};
+/// # #[delegatable_trait]
+/// # pub trait Shout {
+/// #     fn shout(&self, input: &str) -> String;
+/// # }
+/// # pub struct Cat;
+/// #
+/// # impl Shout for Cat {
+/// #     fn shout(&self, input: &str) -> String {
+/// #         format!("{} - meow!", input)
+/// #     }
+/// # }
+///
+/// #[derive(Delegate)]
+/// #[delegate(Shout, unwrap)] // <-------- Panics with "`0` field of `LazyCat` is `None`" if not initialized
+/// pub struct LazyCat(Option<Cat>);
+/// ```
+///
+/// #### `#[delegate(..., interior_mut)]` - delegating to a `RefCell` field
+/// With the `interior_mut` flag the target field is a `RefCell` and the methods are called on the value inside it:
+/// methods taking `&self` go through `borrow()`, those taking `&mut self` through `borrow_mut()`
+/// and those taking `self` through `into_inner()`. The automatic where clause bounds the type inside the `RefCell`.
+/// Like any use of `RefCell` this panics if the value is already mutably borrowed, and methods returning references can't be delegated.
+///
+/// #### `#[delegate(..., lock)]` and `#[delegate(..., rwlock)]` - delegating to a `Mutex` or `RwLock` field
+/// The `lock` flag works the same way for a `Mutex` field, which is locked for the duration of each call.
+/// With the `rwlock` flag an `RwLock` field is locked with `read()` for methods taking `&self` and `write()` for those taking `&mut self`.
+/// A poisoned lock panics through `unwrap()`, or through `expect()` with the `poisoned` value as message
+/// (eg. `#[delegate(Counter, lock, poisoned = "counter lock was poisoned")]`).
+///
+/// #### `#[delegate(..., target_owned = "...", target_ref = "...", target_mut = "...")]` - per receiver targets
+/// Different targets can be used depending on the receiver of the trait method being delegated,
+/// which is useful when the delegate is only reachable through separate accessors for `&self` and `&mut self`.
+/// Each of these keys accepts a field or an expression like `target`, and `target` is used for the receivers that aren't specified.
+/// As with expression targets, no automatic where clause is added.
+///
+/// ```
+/// # use ambassador::{Delegate, delegatable_trait};
+/// # #[delegatable_trait]
+/// # pub trait Shout {
+/// #     fn shout(&self, input: &str) -> String;
+/// #     fn rename(&mut self, name: &str);
+/// # }
+/// # pub struct Cat;
+/// #
+/// # impl Shout for Cat {
+/// #     fn shout(&self, input: &str) -> String {
+/// #         format!("{} - meow!", input)
+/// #     }
+/// #     fn rename(&mut self, name: &str) {}
+/// # }
+///
+/// #[derive(Delegate)]
+/// #[delegate(Shout, target_ref = "self.inner()", target_mut = "self.inner_mut()")]
+/// pub struct BoxedCat(Box<Cat>);
+///
+/// impl BoxedCat {
+///     fn inner(&self) -> &Cat {
+///         &self.0
+///     }
+///
+///     fn inner_mut(&mut self) -> &mut Cat {
+///         &mut self.0
+///     }
+/// }
+/// ```
 ///
 /// #### `#[delegate(..., target = "self")]` - `target="self"`
 /// Types that implement all the methods of a trait without implementing the trait itself,
@@ -215,7 +422,10 @@ use crate::register::build_register_trait;
 ///
 /// To make a delegation apply only for certain generic bounds, similar to a [native where clause](https://doc.rust-lang.org/stable/rust-by-example/generics/where.html), you can specify a `where` attribute:
 ///
-/// A where clause is automatically applied that makes sure the target field implements the trait being delegated
+/// A where clause is automatically applied that makes sure the target field implements the trait being delegated.
+/// It bounds the whole field type (eg. `Wrapper<T>: Shout` rather than `T: Shout`), so it is usually all that is needed
+/// for generic types, and the `where` key is only required to restrict the impl further.
+/// Type parameters that only appear in other fields (eg. a `PhantomData<T>` marker) are not bounded at all.
 /// ```
 /// # use ambassador::{Delegate, delegatable_trait};
 /// # #[delegatable_trait]
@@ -232,6 +442,21 @@ use crate::register::build_register_trait;
 /// }
 /// ```
 ///
+/// The predicates can also be written without a string literal, eg. `#[delegate(Shout, where(A: Debug, A: Clone))]`,
+/// both forms can be mixed in the same attribute.
+///
+/// Predicates may also bound `Self`, eg. `where = "Self: Send"`, which refers to the type the trait is implemented for.
+///
+/// The `where` key can be given more than once in the same attribute, eg. `#[delegate(Shout, where = "A: Debug", where = "A: Clone")]`.
+/// The generated where clause lists these predicates in the order they were given, followed by the type's own where clause
+/// and finally the automatic bound on the target; predicates that appear more than once are only emitted once.
+///
+/// For a trait with supertraits (eg. `trait Pet: Animal`) the automatic bound on the target (eg. `A: Pet`) also implies
+/// the supertrait bounds (`A: Animal`), so the supertrait methods can be used on the target without extra `where` predicates.
+/// The delegating type itself still needs its own impl of each supertrait, which is usually another `#[delegate(Animal)]`.
+/// For enums the variants are only required to agree on the associated types of the delegated trait itself,
+/// agreement on those of a supertrait must be added explicitly (eg. `where = "B: Map<K = A::K, V = A::V>"`).
+///
 ///
 /// #### `#[delegate(Shout<X>, generics = "X")]` - trait generics
 ///
@@ -264,6 +489,257 @@ use crate::register::build_register_trait;
 /// pub struct WrappedCat(Cat);
 /// ```
 ///
+/// Generic parameters of the delegating type itself can be used in the trait path without listing them in `generics`,
+/// eg. `#[delegate(Reader<'a>)]` on `struct Counting<'a>` only delegates `Reader` for the struct's own lifetime.
+///
+/// Associated type bindings such as `#[delegate(Converter<i32, Error = MyErr>)]` can also be used.
+/// They are left out of the generated impl header (the associated types still come from the delegate)
+/// and are instead checked by the automatic where clause.
+///
+/// For enums every variant has to implement the same instantiation of a generic trait (eg. `Convert<u8>`),
+/// a variant that only implements another one (eg. `Convert<u16>`) is reported at its field.
+///
+/// #### `#[delegate(Shout, exclude(whisper))]` - excluding methods
+///
+/// Methods listed in `exclude(...)` are left out of the generated impl, so the trait's default implementation is used for them.
+/// This means only methods with a default implementation can be excluded, and naming a method the trait doesn't have is an error.
+///
+/// ```
+/// # use ambassador::{Delegate, delegatable_trait};
+/// #[delegatable_trait]
+/// pub trait Shout {
+///     fn shout(&self, input: &str) -> String;
+///     fn whisper(&self, input: &str) -> String {
+///         input.to_lowercase()
+///     }
+/// }
+/// # pub struct Cat;
+/// #
+/// # impl Shout for Cat {
+/// #     fn shout(&self, input: &str) -> String {
+/// #         format!("{} - meow!", input)
+/// #     }
+/// # }
+///
+/// #[derive(Delegate)]
+/// #[delegate(Shout, exclude(whisper))] // <-------- WrappedCat uses the default `whisper`
+/// pub struct WrappedCat(Cat);
+/// ```
+///
+/// #### `#[delegate(Shout, include(shout))]` - including methods
+///
+/// The opposite of `exclude`: only the methods listed in `include(...)` are delegated and all others use their default implementation.
+/// `include` and `exclude` can't be combined in the same `#[delegate]` attribute.
+///
+/// #### `#[delegate(Animal, route(name = "foo", sound = "bar"))]` - routing methods to different fields
+///
+/// For structs composed of several parts, each method can be delegated to its own field with `route(method = "field", ..)`.
+/// Methods that aren't routed are delegated to `target` (or the only field) along with associated types and constants.
+/// When there is no such target, the methods that aren't routed use their default implementation,
+/// and it is an error for a method without one to have no route.
+///
+/// ```
+/// # use ambassador::{Delegate, delegatable_trait};
+/// # #[delegatable_trait]
+/// # pub trait Animal {
+/// #     fn name(&self) -> String;
+/// #     fn sound(&self) -> String;
+/// # }
+/// # pub struct Name;
+/// # impl Animal for Name {
+/// #     fn name(&self) -> String { "Tom".to_owned() }
+/// #     fn sound(&self) -> String { unimplemented!() }
+/// # }
+/// # pub struct Voice;
+/// # impl Animal for Voice {
+/// #     fn name(&self) -> String { unimplemented!() }
+/// #     fn sound(&self) -> String { "meow".to_owned() }
+/// # }
+/// #[derive(Delegate)]
+/// #[delegate(Animal, route(name = "name", sound = "voice"))]
+/// pub struct Cat {
+///     name: Name,
+///     voice: Voice,
+/// }
+/// ```
+///
+/// #### `#[delegate(Builder, from_inner = "Self")]` - methods returning `Self`
+///
+/// A trait method returning `Self` (eg. `fn with_flag(self, flag: bool) -> Self`) returns the target's type when called on the target.
+/// The `from_inner` key takes a function or closure converting that value back into the implementer,
+/// which is applied to the result of every delegated method returning `Self`. It can't be used for enums.
+///
+/// ```
+/// # use ambassador::{Delegate, delegatable_trait};
+/// #[delegatable_trait]
+/// pub trait Builder {
+///     fn with_flag(self, flag: bool) -> Self;
+/// }
+/// # pub struct Options(bool);
+/// # impl Builder for Options {
+/// #     fn with_flag(self, flag: bool) -> Self {
+/// #         Options(flag)
+/// #     }
+/// # }
+///
+/// #[derive(Delegate)]
+/// #[delegate(Builder, from_inner = "Self")] // <-------- `with_flag` returns `Self(self.0.with_flag(flag))`
+/// pub struct WrappedOptions(Options);
+/// ```
+///
+/// With the `default_fields` flag instead of a `from_inner` value, the implementer is built from the field's value
+/// and every other field is set to its own default. This makes `Default` (registered with `#[delegatable_trait_remote]`)
+/// delegatable to a field of a struct with several fields (eg. `#[delegate(Default, target = "config", default_fields)]`),
+/// as long as the other fields all implement `Default`.
+///
+/// Associated functions without a receiver (eg. `fn new(name: &str) -> Self` or `From::from`) are called on the target's type,
+/// so combined with `from_inner` traits like `From` can be delegated as well. They can't be delegated for enums.
+///
+/// #### `#[delegate(Shout, cfg = "feature = \"shout\"")]` - conditional impls
+///
+/// The generated impl (or module for enums) is marked with `#[cfg(...)]` using the given predicate,
+/// so it is only compiled in when the predicate holds.
+///
+/// #### `#[delegate(Yell, as = "Shout")]` - traits imported under another name
+///
+/// The helper macro used for delegation is named after the trait's name at the `#[delegatable_trait]` definition,
+/// but is looked up using the last segment of the path in the `#[delegate]` attribute.
+/// When the trait is imported or re-exported under a different name, or was registered under another name
+/// with `#[delegatable_trait(as = "...")]` (see [`macro@delegatable_trait`]), `as` gives the name it was registered under:
+/// ```
+/// # use ambassador::{delegatable_trait, Delegate};
+/// mod animals {
+///     # use ambassador::delegatable_trait;
+///     #[delegatable_trait]
+///     pub trait Shout {
+///         fn shout(&self, input: &str) -> String;
+///     }
+/// }
+/// use animals::Shout as Yell;
+/// # pub struct Cat;
+/// # impl Yell for Cat {
+/// #     fn shout(&self, input: &str) -> String {
+/// #         format!("{} - meow!", input)
+/// #     }
+/// # }
+///
+/// #[derive(Delegate)]
+/// #[delegate(Yell, as = "Shout")]
+/// pub struct WrappedCat(Cat);
+/// # fn main() {}
+/// ```
+///
+/// #### `#[delegate(Shout, inline)]` - inlining
+///
+/// With the `inline` flag every generated method is marked `#[inline(always)]`,
+/// which can help hot delegation paths at the cost of larger code. By default no inline attribute is added.
+///
+/// #### `#[delegate(Shout, doc)]` - documenting delegated methods
+///
+/// Rustdoc shows the trait's documentation for the methods of a delegated impl, as for any other trait impl.
+/// With the `doc` flag every generated method is instead documented as delegated
+/// (eg. "Delegated to the target's implementation of `Shout`."), which makes forwarding impls easy to tell apart.
+///
+/// #### `#[delegate(Shout, hygienic)]` - calling methods through the trait
+///
+/// The generated impls are always marked `#[automatically_derived]`, and enum arms bind their variant's field as `ambassador_inner`
+/// so it can't clash with the method's parameters. Methods of struct targets and enum variants are called through the trait
+/// (eg. `<Cat as Shout>::shout(&self.0, input)` or `Shout::shout(ambassador_inner, input)`), so an inherent method of the target type
+/// with the same name is never picked over the trait's, as method call syntax (`self.0.shout(input)`) would.
+/// This also resolves the ambiguity when another trait in scope has a method of the same name, eg. when delegating
+/// a supertrait to a field whose type also implements a subtrait redeclaring the method (`trait Titled: Named { fn name(&self) ... }`).
+/// Expression and receiver targets, whose type isn't known, variants holding a trait object behind a pointer
+/// and impls without the automatic where clause keep method call syntax.
+/// With the `hygienic` flag impls without the automatic where clause call through the trait as well, so the target
+/// must implement it instead of only having compatible methods. The flag can't be used with expression or receiver targets.
+///
+/// #### `#[delegate(Animal, ref_only)]` - delegating only `&self` methods
+///
+/// With the `ref_only` flag only the methods taking `&self` (and associated functions) are delegated,
+/// methods taking `self` or `&mut self` keep their default implementation. This allows delegating through
+/// pointers that only give shared access like `Arc<T>` (eg. `#[delegate(Animal, target = "*", ref_only)]`).
+/// A method taking `self` or `&mut self` without a default implementation is an error.
+///
+/// #### `#[delegate(Animal, receivers = "ref, ref_mut")]` - delegating only methods with certain receivers
+///
+/// The `receivers` value generalizes `ref_only` to any set of `value` (`self`), `ref` (`&self`) and `ref_mut` (`&mut self`),
+/// eg. `receivers = "ref"` is the same as `ref_only`. Only methods with one of the listed receivers (and associated functions)
+/// are delegated and targets are only needed for those receivers, the other methods have to have a default implementation.
+///
+/// #### `#[delegate(Animal, only_required)]` - delegating only required methods
+///
+/// With the `only_required` flag only the methods without a default implementation in the trait are delegated,
+/// the provided methods keep their default implementation, which then calls the delegated required methods of the implementer.
+/// This is useful when the defaults are written in terms of the required methods and should see the implementer's other overrides.
+/// Associated types and constants are still delegated, and targets are only needed for the receivers of the required methods.
+///
+/// #### `#[delegate(Zeroable, unsafe)]` - delegating an `unsafe trait`
+///
+/// Implementing an `unsafe trait` requires an `unsafe impl`, which the macro can't tell from the trait's name alone.
+/// With the `unsafe` flag the generated impl is declared `unsafe impl`, the delegated type's own `unsafe impl`
+/// of the trait must uphold the same invariants. The compiler reports the flag being missing or unnecessary.
+///
+/// #### `#[delegate(Legs, const)]` - delegating a `const trait` (nightly only)
+///
+/// Rust doesn't allow `const fn` methods in traits or trait impls, a method's signature is copied as is
+/// so such a method is reported by the compiler like in any other trait. Instead, with the unstable `const_trait_impl` feature
+/// a whole trait is declared `const trait` (which `#[delegatable_trait]` accepts) and implemented with `impl const`.
+/// With the `const` flag the generated impl is declared `impl const`, so its methods can be called in constant expressions
+/// when the target's impl is `const` as well. The automatic where clause bounds the target with the plain trait,
+/// so this works for concrete target types but not for generic ones, which would need a `[const]` bound.
+///
+/// #### `#[delegate(Shout, module = "shout_impl")]` - module name for enums
+///
+/// The impl generated for an enum is placed in a private module named `ambassador_module_{Trait}_for_{Enum}`.
+/// When the same trait is delegated more than once on an enum (eg. with different generic arguments)
+/// these names clash, so a different module name can be chosen with the `module` key.
+/// The impl generated for a struct is placed next to the struct, unless a module is requested with the `module` key
+/// as well, which keeps any helper items of the generated code out of the surrounding scope.
+///
+/// ```
+/// # use ambassador::{Delegate, delegatable_trait};
+/// # #[delegatable_trait]
+/// # pub trait Shout<T> {
+/// #     fn shout(&self, input: T) -> String;
+/// # }
+/// # pub struct Cat;
+/// # impl<T: std::fmt::Display> Shout<T> for Cat {
+/// #     fn shout(&self, input: T) -> String {
+/// #         format!("{} - meow!", input)
+/// #     }
+/// # }
+/// #[derive(Delegate)]
+/// #[delegate(Shout<i32>, module = "shout_i32")]
+/// #[delegate(Shout<bool>, module = "shout_bool")]
+/// pub enum Animals {
+///     Cat(Cat),
+/// }
+/// # fn main() {}
+/// ```
+///
+/// #### `#[delegate(Shout, no_module)]` - enums without the generated module
+///
+/// The generated module imports the surrounding items with `use super::*`, which misses items that aren't visible that way,
+/// such as types declared inside a function body. With the `no_module` flag the impl is instead placed in an anonymous
+/// `const _: () = { ... };` block, which sees the same names as the enum itself.
+/// The hidden `Match{Trait}` helper trait is scoped to that block, so associated types and constants are still delegated.
+/// It can't be combined with the `module` key.
+///
+/// #### `#[delegate(Shout, per_variant)]` - independent enum variants
+///
+/// By default the variants of an enum are required to agree on the trait's associated types,
+/// which is expressed through a hidden `Match{Trait}` helper trait in the where clause (eg. `B: MatchShout<A>`).
+/// For a trait without associated types this bound only requires `B: Shout`, so the variants can hold unrelated types either way.
+/// With the `per_variant` flag each variant type is simply bounded by the trait (eg. `A: Shout, B: Shout`),
+/// which gives simpler bounds and error messages but only works for traits without associated types or constants.
+/// A variant type that doesn't implement the trait is then reported as eg. ``the trait bound `B: Shout` is not satisfied``
+/// at the `#[delegate]` attribute, without the `Match{Trait}` helper trait showing up in the error.
+///
+/// #### `#[delegate(Shout, auto_bounds = "Send + Sync")]` - extra bounds on the target
+///
+/// The automatic where clause only requires the target to implement the trait, auto traits like `Send` and `Sync`
+/// aren't touched. The `auto_bounds` value appends more bounds to it (eg. `T: Shout + Send + Sync`),
+/// for enums to every variant type. It has no effect with `automatic_where_clause = "false"`.
 ///
 /// #### `#[delegate(Shout, automatic_where_clause = "false")]` - inhibit automatic generation of `where` clause.
 ///
@@ -287,7 +763,8 @@ use crate::register::build_register_trait;
 /// implement `MyTrait`.
 ///
 /// `automatic_where_clause = "false"` lets us create a delegated impl of
-/// `MyTrait` that takes advantage of this.
+/// `MyTrait` that takes advantage of this. The target's methods are then called with method call syntax
+/// (eg. `self.0.shout(input)`), so inherent methods and methods reached through auto-deref (eg. of `Rc<Cat>`) are found too.
 ///
 /// ```
 /// use ambassador::{delegatable_trait, Delegate};
@@ -314,6 +791,9 @@ use crate::register::build_register_trait;
 /// fn recording_studio<S: Shout>(voice_actor: S){}
 /// ```
 ///
+/// The `no_auto_bound` flag (`#[delegate(Shout, no_auto_bound)]`) is a shorter way to write `automatic_where_clause = "false"`.
+/// It is also useful when the automatic bound is redundant, eg. with a blanket impl that is already implied by a bound from the `where` key.
+///
 /// Note that it is also possible to create such a delegated impl by making use
 /// of [`macro@delegate_to_remote_methods`] with [`Deref::deref`] and
 /// [`DerefMut::deref_mut`] as the target methods. The docs on
@@ -335,7 +815,7 @@ pub fn delegate_macro(input: TokenStream) -> TokenStream {
 /// Excluding some of these attributes is allowed as long as the trait being delegated to doesn't have any methods with the relevant receiver
 /// Additional methods that don't have any of the relevant signature types may be included in the impl block as long as they are never used as targets.
 ///
-/// #### The `where` and `generics` keys described in [`Delegate`] are also supported and function the same way
+/// #### The `where`, `generics`, `exclude`, `include`, `inline`, `doc`, `ref_only`, `receivers`, `only_required`, `unsafe`, `const`, `no_auto_bound`, `auto_bounds`, `from_inner`, `cfg` and `as` keys described in [`Delegate`] are also supported and function the same way
 ///
 /// ```
 /// use std::ops::{Deref, DerefMut};
@@ -725,6 +1205,41 @@ pub fn delegate_remote(_attr: TokenStream, input: TokenStream) -> TokenStream {
     derive::delegate_macro(input)
 }
 
+/// Forward inherent methods of a field, which aren't part of any trait, by declaring their signatures
+///
+/// The methods are declared without a body in an inherent `impl` block of the type holding the field,
+/// each of them then calls the method of the same name on the field given by `target`:
+///
+/// ```
+/// use ambassador::delegate_inherent;
+///
+/// pub struct Stack {
+///     items: Vec<u32>,
+/// }
+///
+/// #[delegate_inherent(target = "items")]
+/// impl Stack {
+///     /// Number of items on the stack
+///     pub fn len(&self) -> usize;
+///     pub fn push(&mut self, value: u32);
+///     pub fn pop(&mut self) -> Option<u32>;
+/// }
+///
+/// let mut stack = Stack { items: Vec::new() };
+/// stack.push(3);
+/// assert_eq!(stack.len(), 1);
+/// assert_eq!(stack.pop(), Some(3));
+/// ```
+///
+/// The macro only sees the declared signatures, so they have to match the field's methods:
+/// the argument and return types are those of the field's method written out (`Self` refers to the type holding the field),
+/// the arguments have to be named rather than patterns, and only methods taking `self`, `&self` or `&mut self` can be forwarded.
+/// Attributes and visibility of the declarations are kept for the generated methods.
+#[proc_macro_attribute]
+pub fn delegate_inherent(attr: TokenStream, input: TokenStream) -> TokenStream {
+    delegate_inherent::delegate_macro(attr, input)
+}
+
 /// Make a trait available for delegation
 ///
 /// This also makes your trait delegatable in other crates:
@@ -737,13 +1252,73 @@ pub fn delegate_remote(_attr: TokenStream, input: TokenStream) -> TokenStream {
 ///     fn shout(&self, input: &str) -> String;
 /// }
 /// ```
+///
+/// Generic methods are delegated with their own parameters and where clause (eg. `fn f<T>(&self, t: T) where T: Clone`),
+/// which the delegated method keeps enforcing.
+///
+/// Delegated `async fn` methods await the target's method.
+/// For traits using `#[async_trait]`, it has to be placed above `#[delegatable_trait]`
+/// so that the desugared methods returning boxed futures are registered, which are then forwarded as they are
+/// (the delegated impls don't need `#[async_trait]`).
+///
+/// Generic associated types (eg. `type Item<'a> where Self: 'a;`) are delegated with their parameters and where clause
+/// (`type Item<'a> = <Target as Trait>::Item<'a> where Self: 'a;`), enums can't delegate traits with them yet.
+///
+/// The helper macro generated for the trait (`ambassador_impl_{Trait}`) is exported at the crate root,
+/// so two traits with the same name in different modules of a crate clash.
+/// One of them can be registered under another name with `#[delegatable_trait(as = "OtherName")]`,
+/// uses then have to refer to it with the matching `as` key, eg. `#[delegate(animals::Shout, as = "AnimalShout")]`:
+///
+/// ```
+/// use ambassador::{delegatable_trait, Delegate};
+///
+/// mod animals {
+///     # use ambassador::delegatable_trait;
+///     #[delegatable_trait(as = "AnimalShout")]
+///     pub trait Shout {
+///         fn shout(&self, input: &str) -> String;
+///     }
+/// }
+///
+/// #[delegatable_trait]
+/// pub trait Shout {
+///     fn speak(&self) -> String;
+/// }
+/// # pub struct Cat;
+/// # impl animals::Shout for Cat {
+/// #     fn shout(&self, input: &str) -> String {
+/// #         format!("{} - meow!", input)
+/// #     }
+/// # }
+/// # impl Shout for Cat {
+/// #     fn speak(&self) -> String {
+/// #         "meow!".to_string()
+/// #     }
+/// # }
+///
+/// #[derive(Delegate)]
+/// #[delegate(animals::Shout, as = "AnimalShout")]
+/// #[delegate(Shout)]
+/// pub struct WrappedCat(Cat);
+/// ```
 #[proc_macro_attribute]
-pub fn delegatable_trait(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    let original_item: syn::ItemTrait = syn::parse(item).unwrap();
-    let register_trait = build_register_trait(&original_item);
+pub fn delegatable_trait(attr: TokenStream, item: TokenStream) -> TokenStream {
+    // The trait is emitted as written, so a `const trait` stays `const`
+    let trait_tokens = proc_macro2::TokenStream::from(item.clone());
+    let item = without_trait_constness(item.into()).into();
+    let original_item = parse_macro_input!(item as syn::ItemTrait);
+    let registered_ident = match parse_register_attr(attr.into()) {
+        Ok(registered_ident) => registered_ident.unwrap_or_else(|| original_item.ident.clone()),
+        Err(err) => {
+            // The trait is still emitted so the error doesn't cascade to its uses
+            let err = err.into_compile_error();
+            return quote!(#trait_tokens #err).into();
+        }
+    };
+    let register_trait = build_register_trait(&original_item, &registered_ident);
 
     let expanded = quote! {
-        #original_item
+        #trait_tokens
 
         #register_trait
     };
@@ -775,10 +1350,41 @@ pub fn delegatable_trait(_attr: TokenStream, item: TokenStream) -> TokenStream {
 /// #[delegate(Display)] // <-------- Delegate implementation of Display to struct field
 /// pub struct WrappedCat(Cat);
 /// ```
+///
+/// Only the copied items have to match the original trait, so a subset of its methods can be delegated
+/// while the others keep their default implementations. Eg. delegating `Iterator` to a newtype over `std::vec::IntoIter`:
+///
+/// ```
+/// use ambassador::{Delegate, delegatable_trait_remote};
+/// use std::iter::Iterator;
+///
+/// #[delegatable_trait_remote]
+/// trait Iterator {
+///     type Item;
+///     fn next(&mut self) -> Option<Self::Item>;
+///     fn size_hint(&self) -> (usize, Option<usize>);
+/// }
+///
+/// #[derive(Delegate)]
+/// #[delegate(Iterator)]
+/// pub struct Queue<T>(std::vec::IntoIter<T>);
+///
+/// let queue = Queue(vec![1, 2, 3].into_iter());
+/// assert_eq!(queue.size_hint(), (3, Some(3)));
+/// assert_eq!(queue.map(|n| n * 2).collect::<Vec<_>>(), [2, 4, 6]);
+/// ```
+///
+/// Operator traits work the same way, eg. `Index<Idx>` copied with its `type Output: ?Sized` and `IndexMut<Idx>: Index<Idx>`
+/// can be delegated with `#[delegate(Index<usize>)]` and `#[delegate(IndexMut<usize>)]`, the `Output` type is taken from the target.
 #[proc_macro_attribute]
-pub fn delegatable_trait_remote(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    let original_item: syn::ItemTrait = syn::parse(item).unwrap();
-    let register_trait = build_register_trait(&original_item);
+pub fn delegatable_trait_remote(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item = without_trait_constness(item.into()).into();
+    let original_item = parse_macro_input!(item as syn::ItemTrait);
+    let registered_ident = match parse_register_attr(attr.into()) {
+        Ok(registered_ident) => registered_ident.unwrap_or_else(|| original_item.ident.clone()),
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let register_trait = build_register_trait(&original_item, &registered_ident);
 
     let expanded = quote! {
         #register_trait
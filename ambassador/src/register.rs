@@ -4,8 +4,8 @@ use proc_macro2::{Ident, TokenStream, TokenTree};
 use quote::{quote, ToTokens, TokenStreamExt};
 use syn::spanned::Spanned;
 use syn::{
-    ConstParam, GenericParam, ItemTrait, LifetimeDef, TraitItem, TraitItemConst, TraitItemType,
-    TypeParam,
+    ConstParam, GenericParam, ItemTrait, LifetimeDef, ReturnType, TraitItem, TraitItemConst,
+    TraitItemType, TypeParam,
 };
 
 pub(crate) fn macro_name(trait_ident: &Ident) -> Ident {
@@ -16,6 +16,101 @@ pub(crate) fn match_name(trait_ident: &Ident) -> Ident {
     quote::format_ident!("Match{}", trait_ident)
 }
 
+/// Receiver types in the order they are listed in a `receivers` filter
+const RECEIVER_TYPES: [ReceiverType; 3] =
+    [ReceiverType::Owned, ReceiverType::Ref, ReceiverType::MutRef];
+
+/// Name of a receiver in a `receivers` filter, also the kind of receiver a method is passed to `filter_method` with
+fn receiver_name(ty: ReceiverType) -> Ident {
+    let name = match ty {
+        ReceiverType::Owned => "value",
+        ReceiverType::Ref => "ref",
+        ReceiverType::MutRef => "ref_mut",
+    };
+    Ident::new(name, proc_macro2::Span::call_site())
+}
+
+/// Filter passed to the registered macro when only the methods with the given receivers are delegated,
+/// eg. `receivers(ref ref_mut)` for `receivers = "ref, ref_mut"`
+pub(crate) fn receivers_filter(receivers: &[ReceiverType]) -> TokenStream {
+    let names = RECEIVER_TYPES
+        .iter()
+        .filter(|ty| receivers.contains(ty))
+        .map(|ty| receiver_name(*ty));
+    quote!(receivers(#(#names)*))
+}
+
+/// Arms of the registered macro for the `receivers` filter: methods with another receiver are skipped
+/// (or reported if they have no default implementation) and don't need a target for their receiver.
+/// `if_receiver` walks the listed receivers and expands to its first block if the given one is among them,
+/// `skipped_required` walks them to list their `self` types in the error
+fn receivers_arms(macro_name: &Ident, trait_ident: &Ident) -> TokenStream {
+    let skipped_error = format!("` of trait `{}` doesn't take ", trait_ident);
+    let mut arms = quote! {
+        (check_filter(receivers $_receivers:tt $($filter:tt)*)) => {
+            #macro_name!{check_filter($($filter)*)}
+        };
+        (filter_assoc([receivers $_receivers:tt $($filter:tt)*], $($item:tt)*)) => {
+            #macro_name!{filter_assoc([$($filter)*], $($item)*)}
+        };
+        (filter_method($method:ident, no_receiver $req:ident, [receivers $_receivers:tt $($filter:tt)*], $($item:tt)*)) => {
+            #macro_name!{filter_method($method, no_receiver $req, [$($filter)*], $($item)*)}
+        };
+        (filter_method($method:ident, $kind:ident provided, [receivers $receivers:tt $($filter:tt)*], $($item:tt)*)) => {
+            #macro_name!{if_receiver($kind, $receivers, {
+                #macro_name!{filter_method($method, $kind provided, [$($filter)*], $($item)*)}
+            }, {})}
+        };
+        (filter_method($method:ident, $kind:ident required, [receivers $receivers:tt $($filter:tt)*], $($item:tt)*)) => {
+            #macro_name!{if_receiver($kind, $receivers, {
+                #macro_name!{filter_method($method, $kind required, [$($filter)*], $($item)*)}
+            }, {
+                #macro_name!{skipped_required($method, $receivers, [])}
+            })}
+        };
+        (if_receiver($_kind:tt, (), $_then:tt, {$($else:tt)*})) => {
+            $($else)*
+        };
+        (skipped_required($method:ident, (), [$_or:literal $($receivers:literal)*])) => {
+            compile_error!{concat!("method `", stringify!($method), #skipped_error, $($receivers,)* " and has no default implementation, so it can't be skipped")}
+        };
+    };
+    let checks = ["check_owned", "check_ref", "check_ref_mut"];
+    for (ty, check) in RECEIVER_TYPES.iter().zip(checks.iter()) {
+        let name = receiver_name(*ty);
+        let check = Ident::new(check, proc_macro2::Span::call_site());
+        let receiver = match ty {
+            ReceiverType::Owned => "`self`",
+            ReceiverType::Ref => "`&self`",
+            ReceiverType::MutRef => "`&mut self`",
+        };
+        arms.extend(quote! {
+            (if_receiver(#name, (#name $($_rest:tt)*), {$($then:tt)*}, $_else:tt)) => {
+                $($then)*
+            };
+            (skipped_required($method:ident, (#name $($rest:tt)*), [$($receivers:literal)*])) => {
+                #macro_name!{skipped_required($method, ($($rest)*), [$($receivers)* " or " #receiver])}
+            };
+            (#check([receivers $receivers:tt $($filter:tt)*] $($target:tt)*)) => {
+                #macro_name!{if_receiver(#name, $receivers, {
+                    #macro_name!{#check([$($filter)*] $($target)*)}
+                }, {})}
+            };
+            (#check([only_required receivers $receivers:tt $($filter:tt)*] $($target:tt)*)) => {
+                #macro_name!{if_receiver(#name, $receivers, {
+                    #macro_name!{#check([only_required $($filter)*] $($target)*)}
+                }, {})}
+            };
+        });
+    }
+    arms.extend(quote! {
+        (if_receiver($kind:tt, ($_first:tt $($rest:tt)*), $then:tt, $else:tt)) => {
+            #macro_name!{if_receiver($kind, ($($rest)*), $then, $else)}
+        };
+    });
+    arms
+}
+
 struct UsedReceivers {
     owned: bool,
     ref_r: bool,
@@ -30,10 +125,41 @@ fn compile_error_or_none(message: &str, return_cmp_err: bool) -> Option<TokenStr
     }
 }
 
-pub fn build_register_trait(original_item: &ItemTrait) -> TokenStream {
+/// Parses the arguments of `#[delegatable_trait]`, returning the name to register the trait under
+pub fn parse_register_attr(attr: TokenStream) -> syn::Result<Option<Ident>> {
+    let parser = |input: syn::parse::ParseStream<'_>| {
+        if input.is_empty() {
+            return Ok(None);
+        }
+        let _: syn::Token![as] = input.parse()?;
+        let _: syn::Token![=] = input.parse()?;
+        let name: syn::LitStr = input.parse()?;
+        Ok(Some(name.parse()?))
+    };
+    syn::parse::Parser::parse2(parser, attr)
+}
+
+/// Removes the `const` of a `const trait` (nightly only), which syn can't parse.
+/// The generated code doesn't depend on it, constant impls are requested by the `const` flag of `#[delegate]`
+pub fn without_trait_constness(item: TokenStream) -> TokenStream {
+    let mut tokens = item.into_iter().peekable();
+    let mut res = TokenStream::new();
+    while let Some(tt) = tokens.next() {
+        let is_const_trait = matches!(&tt, TokenTree::Ident(ident) if ident == "const")
+            && matches!(tokens.peek(), Some(TokenTree::Ident(ident)) if ident == "trait");
+        if !is_const_trait {
+            res.append(tt);
+        }
+    }
+    res
+}
+
+/// `registered_ident` is the name used for the helper macro and to refer to the trait in the generated code,
+/// it is the trait's own name unless another one is given with `#[delegatable_trait(as = "...")]`
+pub fn build_register_trait(original_item: &ItemTrait, registered_ident: &Ident) -> TokenStream {
     let trait_ident = &original_item.ident;
-    let macro_name = macro_name(trait_ident);
-    let match_name = match_name(trait_ident);
+    let macro_name = macro_name(registered_ident);
+    let match_name = match_name(registered_ident);
     let gen_params = &original_item.generics.params;
     let gen_idents: Vec<_> = gen_params.iter().map(param_to_ident).collect();
     let gen_matcher: TokenStream = gen_params.iter().map(param_to_matcher).collect();
@@ -43,16 +169,63 @@ pub fn build_register_trait(original_item: &ItemTrait) -> TokenStream {
         ref_r: false,
         ref_mut: false,
     };
-    let iter = original_item
+    let iter = original_item.items.iter().map(|item| {
+        build_trait_items(
+            item,
+            registered_ident,
+            &macro_name,
+            &gen_idents,
+            &mut used_recievers,
+        )
+    });
+    let (struct_items, struct_expr_items, enum_items, self_items): (
+        Vec<_>,
+        Vec<_>,
+        Vec<_>,
+        Vec<_>,
+    ) = match process_results(iter, |iter| iter.multiunzip()) {
+        Ok(tup) => tup,
+        Err(err) => return err.into_compile_error(),
+    };
+    let method_idents: Vec<_> = original_item
         .items
         .iter()
-        .map(|item| build_trait_items(item, trait_ident, &gen_idents, &mut used_recievers));
-    let (struct_items, enum_items, self_items): (Vec<_>, Vec<_>, Vec<_>) =
-        match process_results(iter, |iter| iter.multiunzip()) {
-            Ok(tup) => tup,
-            Err(err) => return err.into_compile_error(),
-        };
-    let assoc_ty_bounds = make_assoc_ty_bound(&original_item.items, original_item, &match_name);
+        .filter_map(|item| match item {
+            TraitItem::Method(method) => Some(&method.sig.ident),
+            _ => None,
+        })
+        .collect();
+    let required_method_idents: Vec<_> = original_item
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            TraitItem::Method(method) if method.default.is_none() => Some(&method.sig.ident),
+            _ => None,
+        })
+        .collect();
+    let provided_method_idents: Vec<_> = original_item
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            TraitItem::Method(method) if method.default.is_some() => Some(&method.sig.ident),
+            _ => None,
+        })
+        .collect();
+    let receivers_arms = receivers_arms(&macro_name, trait_ident);
+    let no_route_error = format!(
+        "` of trait `{}` has no route and no default implementation",
+        trait_ident
+    );
+    let not_a_method_error = format!(
+        "` is not a method of trait `{}` and can not be ",
+        trait_ident
+    );
+    let assoc_ty_bounds = make_assoc_ty_bound(
+        &original_item.items,
+        original_item,
+        registered_ident,
+        &match_name,
+    );
     let gen_idents_pat: TokenStream = gen_idents.into_iter().map(|id| quote! {$ #id ,}).collect();
     let check_owned = compile_error_or_none(
         "target_owned was not specified but was needed",
@@ -66,6 +239,37 @@ pub fn build_register_trait(original_item: &ItemTrait) -> TokenStream {
         "target_mut was not specified but was needed",
         used_recievers.ref_mut,
     );
+    // The receivers of the methods without a default implementation, which are the only ones delegated with `only_required`
+    let mut required_recievers = UsedReceivers {
+        owned: false,
+        ref_r: false,
+        ref_mut: false,
+    };
+    for item in &original_item.items {
+        match item {
+            TraitItem::Method(method) if method.default.is_none() => {
+                match receiver_type(&method.sig) {
+                    Ok(ReceiverType::Owned) => required_recievers.owned = true,
+                    Ok(ReceiverType::Ref) => required_recievers.ref_r = true,
+                    Ok(ReceiverType::MutRef) => required_recievers.ref_mut = true,
+                    Err(_) => {}
+                }
+            }
+            _ => {}
+        }
+    }
+    let check_owned_required = compile_error_or_none(
+        "target_owned was not specified but was needed",
+        required_recievers.owned,
+    );
+    let check_ref_required = compile_error_or_none(
+        "target_ref was not specified but was needed",
+        required_recievers.ref_r,
+    );
+    let check_ref_mut_required = compile_error_or_none(
+        "target_mut was not specified but was needed",
+        required_recievers.ref_mut,
+    );
     let mut register_trait = quote! {
         #[doc(hidden)]
         #[macro_export]
@@ -74,27 +278,162 @@ pub fn build_register_trait(original_item: &ItemTrait) -> TokenStream {
                 #macro_name!{body_struct(<#gen_idents_pat>, $ty, ($field_ident), ($field_ident), ($field_ident))}
             };
             (body_struct(<#gen_matcher>, $ty:ty, ($($ident_owned:tt)*), ($($ident_ref:tt)*), ($($ident_ref_mut:tt)*))) => {
-                #macro_name!{check_owned($($ident_owned)*)}
-                #macro_name!{check_ref($($ident_ref)*)}
-                #macro_name!{check_ref_mut($($ident_ref_mut)*)}
+                #macro_name!{body_struct([exclude()], [], [], <#gen_idents_pat>, $ty, ($($ident_owned)*), ($($ident_ref)*), ($($ident_ref_mut)*))}
+            };
+            (body_struct([$($filter:tt)*], [$($method_attr:tt)*], [$($from_inner:tt)*], <#gen_matcher>, $ty:ty, ($($ident_owned:tt)*), ($($ident_ref:tt)*), ($($ident_ref_mut:tt)*))) => {
+                #macro_name!{check_filter($($filter)*)}
+                #macro_name!{check_owned([$($filter)*] $($ident_owned)*)}
+                #macro_name!{check_ref([$($filter)*] $($ident_ref)*)}
+                #macro_name!{check_ref_mut([$($filter)*] $($ident_ref_mut)*)}
                 #(#struct_items)*
             };
-            (check_owned()) => {
+            (body_struct_expr([$($filter:tt)*], [$($method_attr:tt)*], [$($from_inner:tt)*], <#gen_matcher>, $ty:ty, $this:ident, ($($expr_owned:tt)*), ($($expr_ref:tt)*), ($($expr_ref_mut:tt)*))) => {
+                #macro_name!{check_filter($($filter)*)}
+                #macro_name!{check_owned([$($filter)*] $($expr_owned)*)}
+                #macro_name!{check_ref([$($filter)*] $($expr_ref)*)}
+                #macro_name!{check_ref_mut([$($filter)*] $($expr_ref_mut)*)}
+                #(#struct_expr_items)*
+            };
+            (call([hygienic $($_filter:tt)*], $_method_call:tt, ($($qualified_call:tt)*))) => {
+                $($qualified_call)*
+            };
+            (call([$($_filter:tt)*], ($($method_call:tt)*), $_qualified_call:tt)) => {
+                $($method_call)*
+            };
+            (wrap_inner([], $($call:tt)*)) => {
+                $($call)*
+            };
+            (wrap_inner([$($from_inner:tt)+], $($call:tt)*)) => {
+                ($($from_inner)+)($($call)*)
+            };
+            #receivers_arms
+            (check_owned([hygienic $($filter:tt)*] $($target:tt)*)) => {
+                #macro_name!{check_owned([$($filter)*] $($target)*)}
+            };
+            // Only the methods without a default implementation need a target with `only_required`
+            (check_owned([only_required $($_filter:tt)*])) => {
+                #check_owned_required
+            };
+            (check_owned([$($_filter:tt)*])) => {
                 #check_owned
             };
-            (check_owned($($_:tt)+)) => {};
-            (check_ref()) => {
+            (check_owned([$($_filter:tt)*] $($_:tt)+)) => {};
+            (check_ref([hygienic $($filter:tt)*] $($target:tt)*)) => {
+                #macro_name!{check_ref([$($filter)*] $($target)*)}
+            };
+            (check_ref([only_required $($_filter:tt)*])) => {
+                #check_ref_required
+            };
+            (check_ref([$($_filter:tt)*])) => {
                 #check_ref
             };
-            (check_ref($($_:tt)+)) => {};
-            (check_ref_mut()) => {
+            (check_ref([$($_filter:tt)*] $($_:tt)+)) => {};
+            (check_ref_mut([hygienic $($filter:tt)*] $($target:tt)*)) => {
+                #macro_name!{check_ref_mut([$($filter)*] $($target)*)}
+            };
+            (check_ref_mut([only_required $($_filter:tt)*])) => {
+                #check_ref_mut_required
+            };
+            (check_ref_mut([$($_filter:tt)*])) => {
                 #check_ref_mut
             };
-            (check_ref_mut($($_:tt)+)) => {};
+            (check_ref_mut([$($_filter:tt)*] $($_:tt)+)) => {};
+            (check_filter(hygienic $($filter:tt)*)) => {
+                #macro_name!{check_filter($($filter)*)}
+            };
+            (check_filter(only_required $($filter:tt)*)) => {
+                #macro_name!{check_filter($($filter)*)}
+            };
+            (check_filter(exclude($($names:ident)*))) => {
+                #macro_name!{check_names(excluded, $($names)*)}
+            };
+            (check_filter(include($($names:ident)*))) => {
+                #macro_name!{check_names(included, $($names)*)}
+            };
+            (check_filter(route($($names:ident)*))) => {
+                #macro_name!{check_names(routed, $($names)*)}
+            };
+            (check_names($action:ident,)) => {};
+            #(
+                (check_names($action:ident, #method_idents $($rest:ident)*)) => {
+                    #macro_name!{check_names($action, $($rest)*)}
+                };
+            )*
+            (check_names($action:ident, $name:ident $($rest:ident)*)) => {
+                compile_error!{concat!("`", stringify!($name), #not_a_method_error, stringify!($action))}
+                #macro_name!{check_names($action, $($rest)*)}
+            };
+            (filter_method($method:ident, $kind:ident $req:ident, [hygienic $($filter:tt)*], $($item:tt)*)) => {
+                #macro_name!{filter_method($method, $kind $req, [$($filter)*], $($item)*)}
+            };
+            // Methods with a default implementation are skipped with `only_required`
+            #(
+                (filter_method(#provided_method_idents, $_kind:ident $_req:ident, [only_required $($_filter:tt)*], $($_item:tt)*)) => {};
+            )*
+            (filter_method($method:ident, $kind:ident $req:ident, [only_required $($filter:tt)*], $($item:tt)*)) => {
+                #macro_name!{filter_method($method, $kind $req, [$($filter)*], $($item)*)}
+            };
+            (filter_method($method:ident, $_kind:ident $_req:ident, [exclude($($names:ident)*)], $($item:tt)*)) => {
+                #macro_name!{skip_excluded($method, ($($names)*), $($item)*)}
+            };
+            (filter_method($method:ident, $_kind:ident $_req:ident, [include($($names:ident)*)], $($item:tt)*)) => {
+                #macro_name!{keep_included($method, ($($names)*), $($item)*)}
+            };
+            (filter_method($method:ident, $_kind:ident $_req:ident, [route($($names:ident)*)], $($item:tt)*)) => {
+                #macro_name!{keep_included($method, ($($names)*), $($item)*)}
+            };
+            (filter_assoc([hygienic $($filter:tt)*], $($item:tt)*)) => {
+                #macro_name!{filter_assoc([$($filter)*], $($item)*)}
+            };
+            (filter_assoc([only_required $($filter:tt)*], $($item:tt)*)) => {
+                #macro_name!{filter_assoc([$($filter)*], $($item)*)}
+            };
+            (filter_assoc([route($($names:ident)*)], $($item:tt)*)) => {};
+            (filter_assoc([$mode:ident($($names:ident)*)], $($item:tt)*)) => {
+                $($item)*
+            };
+            (check_routed($($names:ident)*)) => {
+                #(#macro_name!{require_route(#required_method_idents, ($($names)*))})*
+            };
+            #(
+                (require_route(#required_method_idents, (#required_method_idents $($_:ident)*))) => {};
+            )*
+            (require_route($method:ident, ())) => {
+                compile_error!{concat!("method `", stringify!($method), #no_route_error)}
+            };
+            (require_route($method:ident, ($_first:ident $($rest:ident)*))) => {
+                #macro_name!{require_route($method, ($($rest)*))}
+            };
+            (skip_excluded($method:ident, (), $($item:tt)*)) => {
+                $($item)*
+            };
+            #(
+                (skip_excluded(#method_idents, (#method_idents $($_:ident)*), $($_item:tt)*)) => {};
+            )*
+            (skip_excluded($method:ident, ($_first:ident $($rest:ident)*), $($item:tt)*)) => {
+                #macro_name!{skip_excluded($method, ($($rest)*), $($item)*)}
+            };
+            (keep_included($method:ident, (), $($_item:tt)*)) => {};
+            #(
+                (keep_included(#method_idents, (#method_idents $($_:ident)*), $($item:tt)*)) => {
+                    $($item)*
+                };
+            )*
+            (keep_included($method:ident, ($_first:ident $($rest:ident)*), $($item:tt)*)) => {
+                #macro_name!{keep_included($method, ($($rest)*), $($item)*)}
+            };
             (body_enum(<#gen_matcher>, $ty:ty, ($( $other_tys:ty ),*), ($( $variants:path ),+))) => {
+                #macro_name!{body_enum([exclude()], [], <#gen_idents_pat>, $ty, ($($other_tys),*), ($($variants {0}),+))}
+            };
+            (body_enum([$($filter:tt)*], [$($method_attr:tt)*], <#gen_matcher>, $ty:ty, ($( $other_tys:ty ),*), ($( $variants:path {$fields:tt} $([$($call_mode:tt)*])? ),*) $(, ($( $skipped:path => $fallback:expr ),*))?)) => {
+                #macro_name!{check_filter($($filter)*)}
                 #(#enum_items)*
             };
             (body_self(<#gen_matcher>)) => {
+                #macro_name!{body_self([exclude()], [], <#gen_idents_pat>)}
+            };
+            (body_self([$($filter:tt)*], [$($method_attr:tt)*], <#gen_matcher>)) => {
+                #macro_name!{check_filter($($filter)*)}
                 #(#self_items)*
             };
             (use_assoc_ty_bounds) => {
@@ -154,9 +493,9 @@ fn param_to_tokens(param: &GenericParam) -> TokenStream {
 fn make_assoc_ty_bound(
     items: &[TraitItem],
     item_trait: &ItemTrait,
+    trait_ident: &Ident,
     match_name: &Ident,
 ) -> TokenStream {
-    let trait_ident = &item_trait.ident;
     let gen_params = &item_trait.generics.params;
     let gen_params_t = super::util::TailingPunctuated(gen_params);
 
@@ -216,82 +555,222 @@ fn replace_gen_idents(tokens: TokenStream, gen_idents: &[&Ident]) -> TokenStream
 fn build_trait_items(
     original_item: &TraitItem,
     trait_ident: &Ident,
+    macro_name: &Ident,
     gen_idents: &[&Ident],
     used_recievers: &mut UsedReceivers,
-) -> syn::Result<(TokenStream, TokenStream, TokenStream)> {
+) -> syn::Result<(TokenStream, TokenStream, TokenStream, TokenStream)> {
     let gen_pat: TokenStream = gen_idents.iter().flat_map(|id| quote! {$#id,}).collect();
     let res = match original_item {
-        TraitItem::Const(TraitItemConst { ident, ty, .. }) => (
-            quote! {
+        TraitItem::Const(TraitItemConst { ident, ty, .. }) => {
+            let item = quote! {
                 const #ident : #ty = <$ty as #trait_ident<#gen_pat>>::#ident;
-            },
-            quote! {
-                const #ident : #ty = {
-                    $(assert!(<$ty as #trait_ident<#gen_pat>>::#ident == <$other_tys as #trait_ident<#gen_pat>>::#ident);)*
-                    <$ty as #trait_ident<#gen_pat>>::#ident
-                };
-            },
-            quote! {compile_error!("trg=\"self\" is not allowed with associated constants")},
-        ),
+            };
+            let item = filter_assoc(macro_name, item);
+            (
+                item.clone(),
+                item,
+                quote! {
+                    const #ident : #ty = {
+                        $(::core::assert!(<$ty as #trait_ident<#gen_pat>>::#ident == <$other_tys as #trait_ident<#gen_pat>>::#ident);)*
+                        <$ty as #trait_ident<#gen_pat>>::#ident
+                    };
+                },
+                quote! {compile_error!("trg=\"self\" is not allowed with associated constants")},
+            )
+        }
         TraitItem::Type(TraitItemType {
             ident, generics, ..
         }) => {
-            let where_clause = &generics.where_clause;
+            // The parameters of a generic associated type are declared with their bounds but passed on without them
+            let (params, args, where_clause) = generics.split_for_impl();
             let item = quote! {
-                type #ident #generics = <$ty as #trait_ident<#gen_pat>>::#ident #generics #where_clause;
+                type #ident #params = <$ty as #trait_ident<#gen_pat>>::#ident #args #where_clause;
             };
             (
-                item.clone(),
+                filter_assoc(macro_name, item.clone()),
+                filter_assoc(macro_name, item.clone()),
                 item,
                 quote! {compile_error!("trg=\"self\" is not allowed with associated types")},
             )
         }
         TraitItem::Method(original_method) => {
-            let method_sig = original_method.sig.to_token_stream();
+            let method_sig = without_param_defaults(&original_method.sig).to_token_stream();
             let method_sig = replace_gen_idents(method_sig, gen_idents);
-            (
-                {
-                    let field_ident = match receiver_type(&original_method.sig)? {
-                        ReceiverType::Owned => {
-                            used_recievers.owned = true;
-                            quote!(self.$($ident_owned)*)
-                        }
-                        ReceiverType::Ref => {
-                            used_recievers.ref_r = true;
-                            quote!(self.$($ident_ref)*)
+            // Attributes like `#[must_use]` and `#[deprecated]` can't be repeated on an impl's methods,
+            // they apply to callers through the trait anyway. Only the forwarder's own call is allowed
+            let method_sig = if original_method
+                .attrs
+                .iter()
+                .any(|attr| attr.path.is_ident("deprecated"))
+            {
+                quote!(#[allow(deprecated)] #method_sig)
+            } else {
+                method_sig
+            };
+            let method_ident = &original_method.sig.ident;
+            let returns_self =
+                matches!(&original_method.sig.output, ReturnType::Type(_, ty) if is_self_type(ty));
+            // The inner value returned in place of `Self` is converted with `from_inner`, if one is given
+            let wrap_inner = |invocation: TokenStream| {
+                if returns_self {
+                    quote!(#macro_name!{wrap_inner([$($from_inner)*], #invocation)})
+                } else {
+                    invocation
+                }
+            };
+            // The `receivers` filter keeps methods with one of its receivers (and associated functions) and skips the others
+            let receiver_kind = match receiver_type(&original_method.sig) {
+                Ok(ty) => receiver_name(ty),
+                Err(_) => Ident::new("no_receiver", proc_macro2::Span::call_site()),
+            };
+            let requirement = if original_method.default.is_none() {
+                quote!(required)
+            } else {
+                quote!(provided)
+            };
+            let filter_method = |item: TokenStream| {
+                quote! {
+                    #macro_name!{filter_method(#method_ident, #receiver_kind #requirement, [$($filter)*], #item)}
+                }
+            };
+            if original_method.sig.receiver().is_none() {
+                // There is no value to delegate to, so the function of the target type is called
+                let method_invocation = build_assoc_fn_invocation(
+                    original_method,
+                    &quote!(<$ty as #trait_ident<#gen_pat>>),
+                );
+                let method_invocation = wrap_inner(method_invocation);
+                let item = quote! {
+                    $($method_attr)*
+                    #method_sig {
+                        #method_invocation
+                    }
+                };
+                let self_invocation = build_assoc_fn_invocation(original_method, &quote!(Self));
+                return Ok((
+                    filter_method(item.clone()),
+                    filter_method(item),
+                    filter_method(quote! {
+                        compile_error!(concat!("associated function `", stringify!(#method_ident), "` without a receiver can not be delegated for enums"));
+                    }),
+                    filter_method(quote! {
+                        #[deny(unconditional_recursion)]
+                        $($method_attr)*
+                        #method_sig {
+                            #self_invocation
                         }
-                        ReceiverType::MutRef => {
-                            used_recievers.ref_mut = true;
-                            quote!(self.$($ident_ref_mut)*)
+                    }),
+                ));
+            }
+            // Matching on `*self` (rather than `self`) keeps the match valid for enums without variants.
+            // The variant's field is bound as `ambassador_inner` so it doesn't shadow the method's parameters
+            let (field_ident, expr, (matched, binding), borrow) =
+                match receiver_type(&original_method.sig)? {
+                    ReceiverType::Owned => {
+                        used_recievers.owned = true;
+                        (
+                            quote!(self.$($ident_owned)*),
+                            quote!($($expr_owned)*),
+                            (quote!(self), quote!(ambassador_inner)),
+                            quote!(),
+                        )
+                    }
+                    ReceiverType::Ref => {
+                        used_recievers.ref_r = true;
+                        (
+                            quote!(self.$($ident_ref)*),
+                            quote!($($expr_ref)*),
+                            (quote!(*self), quote!(ref ambassador_inner)),
+                            quote!(&),
+                        )
+                    }
+                    ReceiverType::MutRef => {
+                        used_recievers.ref_mut = true;
+                        (
+                            quote!(self.$($ident_ref_mut)*),
+                            quote!($($expr_ref_mut)*),
+                            (quote!(*self), quote!(ref mut ambassador_inner)),
+                            quote!(&mut),
+                        )
+                    }
+                };
+            // In the `hygienic` call mode (used by the derive for struct targets and variants) the method is called through the trait,
+            // so inherent methods of the target type with the same name can't be picked instead
+            let call = |target: &TokenStream, arguments: &TokenStream| {
+                let method_invocation =
+                    build_method_invocation(&original_method.sig, target, arguments);
+                let trait_path = quote!(<$ty as #trait_ident<#gen_pat>>);
+                let qualified_invocation = build_qualified_invocation(
+                    original_method,
+                    &trait_path,
+                    &quote!(#borrow #target),
+                    arguments,
+                );
+                quote!(#macro_name!{call([$($filter)*], (#method_invocation), (#qualified_invocation))})
+            };
+            let arguments = argument_list(&original_method.sig);
+            let (struct_item, struct_expr_item, enum_item, self_item) = (
+                {
+                    let arguments = projected_argument_list(&original_method.sig);
+                    let method_invocation = wrap_inner(call(&field_ident, &arguments));
+                    quote! {
+                        $($method_attr)*
+                        #method_sig {
+                            #method_invocation
                         }
-                    };
-                    let method_invocation = build_method_invocation(original_method, &field_ident);
+                    }
+                },
+                {
+                    let method_invocation = wrap_inner(call(&expr, &arguments));
                     quote! {
+                        $($method_attr)*
                         #method_sig {
+                            let $this = self;
                             #method_invocation
                         }
                     }
                 },
                 {
-                    let method_invocation =
-                        build_method_invocation(original_method, &quote!(inner));
+                    // The binding already borrows the field like the receiver, and the type of `Self` is inferred from it.
+                    // Each variant passes its own call mode, trait objects behind a pointer need method call syntax
+                    let method_invocation = build_method_invocation(
+                        &original_method.sig,
+                        &quote!(ambassador_inner),
+                        &arguments,
+                    );
+                    let qualified_invocation = build_qualified_invocation(
+                        original_method,
+                        &quote!(#trait_ident::<#gen_pat>),
+                        &quote!(ambassador_inner),
+                        &arguments,
+                    );
                     quote! {
+                        $($method_attr)*
                         #method_sig {
-                            match self {
-                                $($variants(inner) => #method_invocation),*
+                            match #matched {
+                                $($variants {$fields: #binding, ..} => #macro_name!{call([$($($call_mode)*)?], (#method_invocation), (#qualified_invocation))},)*
+                                $($($skipped {..} => $fallback,)*)?
                             }
                         }
                     }
                 },
                 {
-                    let method_invocation = build_method_invocation(original_method, &quote!(self));
+                    let method_invocation =
+                        build_method_invocation(&original_method.sig, &quote!(self), &arguments);
                     quote! {
                         #[deny(unconditional_recursion)]
+                        $($method_attr)*
                         #method_sig {
                             #method_invocation
                         }
                     }
                 },
+            );
+            (
+                filter_method(struct_item),
+                filter_method(struct_expr_item),
+                filter_method(enum_item),
+                filter_method(self_item),
             )
         }
         _ => return error!(original_item.span(), "unsupported trait item"),
@@ -299,12 +778,38 @@ fn build_trait_items(
     Ok(res)
 }
 
-fn build_method_invocation(
-    original_method: &syn::TraitItemMethod,
-    field_ident: &TokenStream,
-) -> TokenStream {
-    let method_sig = &original_method.sig;
-    let method_ident = &method_sig.ident;
+// Associated items are only delegated once, by the main target, not by the targets of routed methods
+fn filter_assoc(macro_name: &Ident, item: TokenStream) -> TokenStream {
+    quote! {
+        #macro_name!{filter_assoc([$($filter)*], #item)}
+    }
+}
+
+/// Defaults of the method's own type and const parameters aren't allowed in an impl,
+/// so they are left out of the delegated method's signature (the trait already reports them)
+fn without_param_defaults(method_sig: &syn::Signature) -> syn::Signature {
+    let mut method_sig = method_sig.clone();
+    for param in &mut method_sig.generics.params {
+        match param {
+            GenericParam::Type(param) => {
+                param.eq_token = None;
+                param.default = None;
+            }
+            GenericParam::Const(param) => {
+                param.eq_token = None;
+                param.default = None;
+            }
+            GenericParam::Lifetime(_) => {}
+        }
+    }
+    method_sig
+}
+
+fn is_self_type(ty: &syn::Type) -> bool {
+    matches!(ty, syn::Type::Path(syn::TypePath { qself: None, path }) if path.is_ident("Self"))
+}
+
+fn argument_list(method_sig: &syn::Signature) -> TokenStream {
     let argument_list: syn::punctuated::Punctuated<&Box<syn::Pat>, syn::token::Comma> = method_sig
         .inputs
         .iter()
@@ -313,7 +818,107 @@ fn build_method_invocation(
             syn::FnArg::Typed(pat_type) => Some(&pat_type.pat),
         })
         .collect();
+    argument_list.into_token_stream()
+}
+
+/// Like `argument_list` but arguments of type `Self`, `&Self` or `&mut Self` are replaced by their field the method is delegated to
+/// (eg. `rhs.0` for `fn add(self, rhs: Self)`), only valid in the `body_struct` arm which gives the field
+fn projected_argument_list(method_sig: &syn::Signature) -> TokenStream {
+    let argument_list: syn::punctuated::Punctuated<TokenStream, syn::token::Comma> = method_sig
+        .inputs
+        .iter()
+        .filter_map(|fn_arg| match fn_arg {
+            syn::FnArg::Receiver(_) => None,
+            syn::FnArg::Typed(pat_type) => Some(pat_type),
+        })
+        .map(|pat_type| {
+            let pat = &pat_type.pat;
+            let ident = match &**pat {
+                syn::Pat::Ident(pat_ident) => &pat_ident.ident,
+                _ => return pat.to_token_stream(),
+            };
+            match &*pat_type.ty {
+                ty if is_self_type(ty) => quote!(#ident.$($ident_owned)*),
+                syn::Type::Reference(reference) if is_self_type(&reference.elem) => {
+                    match reference.mutability {
+                        Some(_) => quote!(&mut #ident.$($ident_ref_mut)*),
+                        None => quote!(&#ident.$($ident_ref)*),
+                    }
+                }
+                _ => pat.to_token_stream(),
+            }
+        })
+        .collect();
+    argument_list.into_token_stream()
+}
+
+pub(crate) fn build_method_invocation(
+    method_sig: &syn::Signature,
+    field_ident: &TokenStream,
+    argument_list: &TokenStream,
+) -> TokenStream {
+    let method_ident = &method_sig.ident;
+    let turbofish = build_turbofish(method_sig);
+    // The delegated `async fn` has to return the output of the target's future rather than the future itself
+    let await_output = method_sig.asyncness.map(|_| quote!(.await));
+    quote! { #field_ident.#method_ident #turbofish(#argument_list) #await_output }
+}
+
+/// Like `build_method_invocation` but calls the method through `trait_path` with `receiver` as its first argument
+fn build_qualified_invocation(
+    original_method: &syn::TraitItemMethod,
+    trait_path: &TokenStream,
+    receiver: &TokenStream,
+    argument_list: &TokenStream,
+) -> TokenStream {
+    let method_sig = &original_method.sig;
+    let method_ident = &method_sig.ident;
+    let turbofish = build_turbofish(method_sig);
+    let await_output = method_sig.asyncness.map(|_| quote!(.await));
+    quote! { #trait_path::#method_ident #turbofish(#receiver, #argument_list) #await_output }
+}
+
+/// Like `build_method_invocation` for associated functions without a receiver, called on `ty`
+fn build_assoc_fn_invocation(
+    original_method: &syn::TraitItemMethod,
+    ty: &TokenStream,
+) -> TokenStream {
+    let method_sig = &original_method.sig;
+    let method_ident = &method_sig.ident;
+    let argument_list = argument_list(method_sig);
+    let turbofish = build_turbofish(method_sig);
+    let await_output = method_sig.asyncness.map(|_| quote!(.await));
+    quote! { #ty::#method_ident #turbofish(#argument_list) #await_output }
+}
+
+// Passes the method's type and const parameters explicitly since they can't always be inferred from the arguments.
+// Lifetimes are left out (they may be late bound) as are methods with `impl Trait` arguments (which don't allow explicit parameters)
+fn build_turbofish(method_sig: &syn::Signature) -> TokenStream {
+    let has_impl_trait_arg = method_sig.inputs.iter().any(|fn_arg| match fn_arg {
+        syn::FnArg::Receiver(_) => false,
+        syn::FnArg::Typed(pat_type) => contains_impl_trait(pat_type.ty.to_token_stream()),
+    });
+    let params: Vec<_> = method_sig
+        .generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(TypeParam { ident, .. }) => Some(ident),
+            GenericParam::Const(ConstParam { ident, .. }) => Some(ident),
+            GenericParam::Lifetime(_) => None,
+        })
+        .collect();
+    if params.is_empty() || has_impl_trait_arg {
+        TokenStream::new()
+    } else {
+        quote!(::<#(#params),*>)
+    }
+}
 
-    let method_invocation = quote! { #field_ident.#method_ident(#argument_list) };
-    method_invocation
+fn contains_impl_trait(tokens: TokenStream) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Group(g) => contains_impl_trait(g.stream()),
+        TokenTree::Ident(id) => id == "impl",
+        _ => false,
+    })
 }
@@ -0,0 +1,264 @@
+//! Snapshot tests of the code generated by `#[derive(Delegate)]`.
+//!
+//! The expected output is stored in `src/snapshots/{name}.snap`,
+//! run the tests with `UPDATE_SNAPSHOTS=1` to accept changes to the generated code after reviewing them.
+
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use std::path::Path;
+
+fn assert_snapshot(name: &str, input: TokenStream) {
+    let output = pretty_print(crate::derive::expand_derive(syn::parse2(input).unwrap()));
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src/snapshots")
+        .join(format!("{}.snap", name));
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(&path, output).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "missing snapshot `{}`, run with UPDATE_SNAPSHOTS=1 to create it",
+            name
+        )
+    });
+    assert_eq!(
+        expected, output,
+        "snapshot `{}` changed, run with UPDATE_SNAPSHOTS=1 to accept it",
+        name
+    );
+}
+
+/// Puts statements and the contents of braces on their own lines so diffs of snapshots stay readable
+fn pretty_print(tokens: TokenStream) -> String {
+    let mut out = String::new();
+    write_tokens(&mut out, tokens, 0);
+    out
+}
+
+fn write_tokens(out: &mut String, tokens: TokenStream, indent: usize) {
+    let mut line = Vec::new();
+    for tt in tokens {
+        match tt {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                write_line(out, &mut line, indent, " {");
+                write_tokens(out, group.stream(), indent + 1);
+                write_line(out, &mut line, indent, "}");
+            }
+            // Keep the semicolon after a closing brace on the same line
+            TokenTree::Punct(punct) if punct.as_char() == ';' && line.is_empty() => {
+                out.pop();
+                out.push_str(";\n");
+            }
+            TokenTree::Punct(punct) if punct.as_char() == ';' => {
+                line.push(TokenTree::Punct(punct));
+                write_line(out, &mut line, indent, "");
+            }
+            tt => line.push(tt),
+        }
+    }
+    write_line(out, &mut line, indent, "");
+}
+
+fn write_line(out: &mut String, line: &mut Vec<TokenTree>, indent: usize, end: &str) {
+    if line.is_empty() && end.is_empty() {
+        return;
+    }
+    out.push_str(&"    ".repeat(indent));
+    if line.is_empty() {
+        out.push_str(end.trim_start());
+    } else {
+        let tokens: TokenStream = line.drain(..).collect();
+        out.push_str(&tokens.to_string());
+        out.push_str(end);
+    }
+    out.push('\n');
+}
+
+#[test]
+fn struct_single_field() {
+    assert_snapshot(
+        "struct_single_field",
+        quote::quote! {
+            #[delegate(Shout)]
+            pub struct WrappedCat(Cat);
+        },
+    );
+}
+
+#[test]
+fn struct_multiple_fields() {
+    assert_snapshot(
+        "struct_multiple_fields",
+        quote::quote! {
+            #[delegate(Shout, target = "foo", where = "T: Clone")]
+            #[delegate(Animal<X>, target = "bar", generics = "X")]
+            pub struct Pets<T> {
+                foo: T,
+                bar: Dog,
+            }
+        },
+    );
+}
+
+#[test]
+fn enum_variants() {
+    assert_snapshot(
+        "enum_variants",
+        quote::quote! {
+            #[delegate(Shout)]
+            pub enum Animals<T> {
+                Cat(Cat),
+                Dog { dog: Dog },
+                Other(T),
+            }
+        },
+    );
+}
+
+#[test]
+fn struct_generic_trait_arg() {
+    assert_snapshot(
+        "struct_generic_trait_arg",
+        quote::quote! {
+            #[delegate(Producer<T>)]
+            pub struct Wrapper<T>(Inner<T>);
+        },
+    );
+}
+
+#[test]
+fn struct_fmt_fields() {
+    assert_snapshot(
+        "struct_fmt_fields",
+        quote::quote! {
+            #[delegate(Display, target = "name")]
+            #[delegate(Debug, target = "id")]
+            pub struct User {
+                id: u32,
+                name: String,
+            }
+        },
+    );
+}
+
+#[test]
+fn enum_skip_variant() {
+    assert_snapshot(
+        "enum_skip_variant",
+        quote::quote! {
+            #[delegate(Shout)]
+            pub enum Animals {
+                Cat(Cat),
+                #[delegate(skip)]
+                Nobody,
+                Dog(Dog),
+            }
+        },
+    );
+}
+
+#[test]
+fn enum_no_module() {
+    assert_snapshot(
+        "enum_no_module",
+        quote::quote! {
+            #[delegate(Shout, no_module)]
+            pub enum Animals {
+                Cat(Cat),
+                Dog(Dog),
+            }
+        },
+    );
+}
+
+#[test]
+fn struct_method_attrs() {
+    assert_snapshot(
+        "struct_method_attrs",
+        quote::quote! {
+            #[delegate(Shout, inline, doc)]
+            pub struct WrappedCat(Cat);
+        },
+    );
+}
+
+#[test]
+fn struct_where_assoc_type_eq() {
+    assert_snapshot(
+        "struct_where_assoc_type_eq",
+        quote::quote! {
+            #[delegate(Iterator, where = "I: Iterator<Item = u8>", where = "I: Clone")]
+            pub struct Bytes<I>(I) where I: Send;
+        },
+    );
+}
+
+#[test]
+fn struct_phantom_generic() {
+    assert_snapshot(
+        "struct_phantom_generic",
+        quote::quote! {
+            #[delegate(Describe<T>, target = "0")]
+            pub struct Tagged<T>(Cat, PhantomData<T>);
+        },
+    );
+}
+
+#[test]
+fn struct_reference_deref() {
+    assert_snapshot(
+        "struct_reference_deref",
+        quote::quote! {
+            #[delegate(Shout, target = "*")]
+            pub struct Borrowed<'a>(&'a Cat);
+        },
+    );
+}
+
+#[test]
+fn struct_same_type_fields() {
+    assert_snapshot(
+        "struct_same_type_fields",
+        quote::quote! {
+            #[delegate(Shout, target = "0", where = "T: Shout")]
+            #[delegate(Name, target = "1")]
+            pub struct Pair<T>(T, T);
+        },
+    );
+}
+
+#[test]
+fn enum_trait_object_variants() {
+    assert_snapshot(
+        "enum_trait_object_variants",
+        quote::quote! {
+            #[delegate(Shout)]
+            pub enum Mixed<'a> {
+                Borrowed(&'a mut dyn Shout),
+                Known(Cat),
+            }
+        },
+    );
+}
+
+#[test]
+fn struct_module() {
+    assert_snapshot(
+        "struct_module",
+        quote::quote! {
+            #[delegate(Shout, module = "shout_impl")]
+            pub struct WrappedCat(Cat);
+        },
+    );
+}
+
+#[test]
+fn struct_deref_box() {
+    assert_snapshot(
+        "struct_deref_box",
+        quote::quote! {
+            #[delegate(Shout, target = "*")]
+            pub struct BoxedCat(Box<Cat>);
+        },
+    );
+}
@@ -0,0 +1,27 @@
+extern crate ambassador;
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait] //~ ERROR associated function `new` without a receiver can not be delegated for enums
+pub trait Animal {
+    fn new() -> Self;
+    fn name(&self) -> String;
+}
+
+pub struct Cat;
+
+impl Animal for Cat {
+    fn new() -> Self {
+        Cat
+    }
+    fn name(&self) -> String {
+        "Tom".to_owned()
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Animal)] //~ ERROR not all trait items implemented, missing: `new`
+pub enum Animals {
+    Cat(Cat),
+}
+
+pub fn main() {}
@@ -0,0 +1,29 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+use std::rc::Rc;
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Rc<Cat> {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, auto_bounds = "Send")]
+pub struct Wrapper<T>(T);
+
+#[derive(Delegate)]
+#[delegate(Shout, auto_bounds = "not a bound")] //~ ERROR expected `+`
+pub struct Invalid(Rc<Cat>);
+
+pub fn main() {
+    Wrapper(Rc::new(Cat)).shout("BAR"); //~ ERROR the method `shout` exists for struct `Wrapper<Rc<Cat>>`, but its trait bounds were not satisfied
+}
@@ -0,0 +1,25 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+// The method's signature is copied as is, so the delegated method is `const` as well
+// (which Rust rejects for trait methods and trait impls alike, const traits are tested in `tests/nightly`)
+#[delegatable_trait]
+pub trait Legs {
+    const fn legs(&self) -> u32; //~ ERROR functions in traits cannot be declared const
+    //~^ ERROR functions in trait impls cannot be declared const
+}
+
+pub struct Cat;
+
+impl Legs for Cat {
+    fn legs(&self) -> u32 {
+        4
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Legs)]
+pub struct Wrapper(Cat);
+
+fn main() {}
@@ -0,0 +1,20 @@
+// edition:2018
+extern crate ambassador;
+
+use ambassador::{delegatable_trait_remote, Delegate};
+
+#[delegatable_trait_remote]
+pub trait Default {
+    fn default() -> Self;
+}
+
+pub struct NoDefault;
+
+#[derive(Delegate)]
+#[delegate(Default, target = "count", default_fields)]
+pub struct Counter {
+    count: u32,
+    other: NoDefault, //~ ERROR the trait bound `NoDefault: Default` is not satisfied
+}
+
+fn main() {}
@@ -0,0 +1,36 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+// A user trait named `Default` isn't special, `Self` isn't built from the other fields without the flag
+#[delegatable_trait] //~ ERROR mismatched types
+pub trait Default {
+    fn default() -> Self;
+}
+
+pub struct Cat;
+
+impl Default for Cat {
+    fn default() -> Self {
+        Cat
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Default, target = "cat")]
+pub struct Owner {
+    name: String,
+    cat: Cat,
+}
+
+#[derive(Delegate)]
+#[delegate(Default, from_inner = "Self", default_fields)] //~ ERROR "default_fields" flag and "from_inner" value on #[delegate] attribute can not be used together
+pub struct Wrapper(Cat);
+
+#[derive(Delegate)]
+#[delegate(Default, default_fields)] //~ ERROR "default_fields" flag on #[delegate] attribute can only be used with a struct field as target
+pub enum Animals {
+    Cat(Cat),
+}
+
+fn main() {}
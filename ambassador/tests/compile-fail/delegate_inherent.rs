@@ -0,0 +1,49 @@
+extern crate ambassador;
+
+use ambassador::delegate_inherent;
+
+pub struct Names(Vec<String>);
+
+#[delegate_inherent] //~ ERROR #[delegate_inherent] needs the field to delegate to, eg. #[delegate_inherent(target = "inner")]
+impl Names {
+    pub fn len(&self) -> usize;
+}
+
+pub struct Stack {
+    items: Vec<u32>,
+}
+
+#[delegate_inherent(field = "items")] //~ ERROR invalid key for #[delegate_inherent] attribute, expected "target"
+impl Stack {
+    pub fn len(&self) -> usize;
+}
+
+#[delegate_inherent(target = "items")]
+impl Clone for Stack { //~ ERROR #[delegate_inherent] can only be used on inherent impl blocks, traits are delegated with #[derive(Delegate)]
+    fn clone(&self) -> Self;
+}
+
+#[delegate_inherent(target = "items")]
+impl Stack {
+    pub fn new() -> Vec<u32>; //~ ERROR function `new` has no receiver, only methods taking `self`, `&self` or `&mut self` can be delegated by #[delegate_inherent]
+}
+
+#[delegate_inherent(target = "items")]
+impl Stack {
+    pub fn push(&mut self, (value, _): (u32, u32)); //~ ERROR arguments of methods delegated by #[delegate_inherent] have to be named
+}
+
+#[delegate_inherent(target = "items")]
+impl Stack {
+    pub fn pop(&mut self) -> Option<u32> { //~ ERROR Only method signatures are allowed here (no blocks!)
+        None
+    }
+}
+
+// The declared signature has to match the field's method
+#[delegate_inherent(target = "items")] //~ ERROR mismatched types
+impl Stack {
+    pub fn first(&self) -> Option<u32>;
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+pub struct Rock;
+
+#[derive(Delegate)]
+// The unimplemented trait is reported for the variant type itself instead of through the `Match` helper trait
+#[delegate(Shout, per_variant)] //~ ERROR the trait bound `Rock: Shout` is not satisfied
+pub enum Things {
+    Cat(Cat),
+    Rock(Rock),
+}
+
+pub fn main() {}
@@ -0,0 +1,50 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub enum Bare {
+    Cat(Cat),
+    #[delegate] //~ ERROR #[delegate] attribute on an enum variant needs the "skip" flag
+    Nobody,
+}
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub enum Unknown {
+    Cat(Cat),
+    #[delegate(ignore)] //~ ERROR invalid key for #[delegate] attribute on an enum variant
+    Nobody,
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target(Cat = "0", Nobody = "0"))] //~ ERROR enum variant `Nobody` is skipped by #[delegate(skip)]
+pub enum Listed {
+    Cat(Cat),
+    #[delegate(skip)]
+    Nobody(Cat),
+}
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub enum BadFallback {
+    Cat(Cat),
+    #[delegate(skip = "0")] //~ ERROR `match` arms have incompatible types
+    Nobody,
+}
+
+pub fn main() {}
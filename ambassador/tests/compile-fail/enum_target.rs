@@ -0,0 +1,30 @@
+extern crate ambassador;
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self) -> String {
+        "meow!".to_owned()
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "*")] //~ ERROR "target" value on #[delegate] attribute can not be specified for enums
+#[delegate(Shout, target = "self.cat()")] //~ ERROR "target" value on #[delegate] attribute can not be specified for enums
+#[delegate(Shout, target = "0.inner")] //~ ERROR "target" value on #[delegate] attribute can not be specified for enums
+#[delegate(Shout, module = "shout", no_module)] //~ ERROR "module" value and "no_module" flag on #[delegate] attribute can not be used together
+pub enum Animals {
+    Cat(Cat),
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, no_module)] //~ ERROR "no_module" flag on #[delegate] attribute can only be specified for enums
+pub struct Wrapped(Cat);
+
+pub fn main() {}
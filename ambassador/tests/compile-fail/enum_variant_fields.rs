@@ -0,0 +1,35 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+pub struct Dog;
+
+impl Shout for Dog {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - wuff!", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub enum Animals {
+    Cat(Cat),
+    Dog(Dog),
+    Pair(Cat, Dog), //~ ERROR enum variant `Pair` has multiple fields
+    Nobody, //~ ERROR enum variant `Nobody` has no fields
+}
+
+pub fn main() {}
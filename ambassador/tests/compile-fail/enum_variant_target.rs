@@ -0,0 +1,32 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "animal")]
+pub enum Animals {
+    Cat { age: u32, animal: Cat },
+    Stray(Cat), //~ ERROR enum variant `Stray` has no field `animal` specified as "target"
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "2")]
+pub enum Pets {
+    Cat(u32, u32, Cat),
+    Stray(Cat, u32), //~ ERROR enum variant `Stray` has only 2 field(s), too few for the field `2` specified as "target"
+}
+
+pub fn main() {}
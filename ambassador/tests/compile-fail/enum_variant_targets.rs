@@ -0,0 +1,36 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target(Tuple = "1"))]
+pub enum Animals {
+    Tuple(u32, Cat),
+    Named { age: u32, animal: Cat }, //~ ERROR enum variant `Named` is missing from the "target" list on #[delegate] attribute
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target(Tuple = "1", Other = "0"))] //~ ERROR enum `Pets` has no variant `Other` specified in the "target" list
+#[delegate(Shout, target = "0", target(Tuple = "1"))] //~ ERROR "target" value and "target" list on #[delegate] attribute can not be used together
+pub enum Pets {
+    Tuple(u32, Cat),
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target(Wrapped = "0"))] //~ ERROR "target" list on #[delegate] attribute can only be specified for enums, use a "target" value for structs
+pub struct Wrapped(Cat);
+
+pub fn main() {}
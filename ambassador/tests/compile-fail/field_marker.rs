@@ -0,0 +1,41 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub struct Twins {
+    #[delegate]
+    first: Cat,
+    #[delegate] //~ ERROR #[delegate] attribute can only be used on one field
+    second: Cat,
+}
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub struct Named {
+    #[delegate(Shout)] //~ ERROR #[delegate] attribute on a field takes no arguments, it only marks the default target
+    cat: Cat,
+    age: u32,
+}
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub enum Animals {
+    Cat(#[delegate] Cat), //~ ERROR #[delegate] attribute on a field can only be used for structs
+}
+
+pub fn main() {}
@@ -0,0 +1,46 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, delegate_to_methods, Delegate};
+
+#[delegatable_trait] //~ ERROR `yell` is not a method of trait `Shout` and can not be excluded
+//~| ERROR `shuot` is not a method of trait `Shout` and can not be included
+//~| ERROR `Voice` is not a method of trait `Shout` and can not be excluded
+pub trait Shout {
+    type Voice;
+    fn shout(&self, input: &str) -> String;
+    fn whisper(&self, input: &str) -> String {
+        input.to_lowercase()
+    }
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    type Voice = ();
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+// The names are checked for enums, `delegate_to_methods` and with `ref_only` as well
+#[derive(Delegate)]
+#[delegate(Shout, exclude(yell))]
+pub enum Animal {
+    Cat(Cat),
+}
+
+pub struct Owner(Cat);
+
+#[delegate_to_methods]
+#[delegate(Shout, target_ref = "cat", include(shuot))] //~ ERROR not all trait items implemented, missing: `shout`
+impl Owner {
+    fn cat(&self) -> &Cat {
+        &self.0
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, exclude(Voice), ref_only)]
+pub struct SharedCat(Cat);
+
+pub fn main() {}
@@ -0,0 +1,24 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Builder {
+    fn with_flag(self, flag: bool) -> Self;
+}
+
+pub struct Options;
+
+impl Builder for Options {
+    fn with_flag(self, _flag: bool) -> Self {
+        self
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Builder, from_inner = "Self::One")] //~ ERROR "from_inner" value on #[delegate] attribute can only be specified for struct fields and expressions
+pub enum Either {
+    One(Options),
+}
+
+pub fn main() {}
@@ -0,0 +1,33 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Convert<T> {
+    fn convert(&self) -> T;
+}
+
+pub struct Small;
+pub struct Large;
+
+impl Convert<u8> for Small {
+    fn convert(&self) -> u8 {
+        1
+    }
+}
+
+impl Convert<u16> for Large {
+    fn convert(&self) -> u16 {
+        2
+    }
+}
+
+// Each variant has to implement the instantiation of the trait being delegated
+#[derive(Delegate)]
+#[delegate(Convert<u8>)]
+pub enum Number {
+    Small(Small),
+    Large(Large), //~ ERROR the trait bound `Large: Convert<u8>` is not satisfied
+}
+
+pub fn main() {}
@@ -0,0 +1,42 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "self.cat()", hygienic)] //~ ERROR "hygienic" flag on #[delegate] attribute can not be used with expression or receiver targets
+#[delegate(Shout, target_ref = "0", hygienic)] //~ ERROR "hygienic" flag on #[delegate] attribute can not be used with expression or receiver targets
+pub struct WrappedCat(Cat);
+
+impl WrappedCat {
+    fn cat(&self) -> &Cat {
+        &self.0
+    }
+}
+
+// `Parrot` only has a compatible inherent method, which the flag doesn't allow calling
+pub struct Parrot;
+
+impl Parrot {
+    pub fn shout(&self, input: &str) -> String {
+        format!("{} - squawk", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, no_auto_bound, hygienic)]
+pub struct P(Parrot); //~ ERROR the trait bound `Parrot: Shout` is not satisfied
+
+pub fn main() {}
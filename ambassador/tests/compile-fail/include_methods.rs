@@ -0,0 +1,29 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait] //~ ERROR `yell` is not a method of trait `Shout` and can not be included
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+    fn whisper(&self, input: &str) -> String {
+        input.to_lowercase()
+    }
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, include(shout, yell))]
+pub struct WrappedCat(Cat);
+
+#[derive(Delegate)]
+#[delegate(Shout, exclude(whisper), include(shout))] //~ ERROR "exclude" and "include" can only be specified once per delegate attribute
+pub struct OtherCat(Cat);
+
+pub fn main() {}
@@ -0,0 +1,35 @@
+extern crate ambassador;
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self) -> String {
+        "meow!".to_owned()
+    }
+}
+
+pub struct Dog;
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "cat[0]")] //~ ERROR Unknown field specified as "target" value in #[delegate] attribute, available fields of `Cats` are: `cats`
+pub struct Cats {
+    cats: Vec<Cat>,
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "0[0]")] //~ ERROR the trait bound `Dog: Shout` is not satisfied
+pub struct Dogs(Vec<Dog>);
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "0[0]")] //~ ERROR "target" value on #[delegate] attribute can not be specified for enums
+pub enum Animals {
+    Cats(Vec<Cat>),
+}
+
+pub fn main() {}
@@ -0,0 +1,33 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+use std::cell::RefCell;
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, interior_mut)]
+pub struct NotCell(Cat); //~ ERROR "interior_mut" flag on #[delegate] attribute requires the target field to be a `RefCell`
+
+#[derive(Delegate)] //~ ERROR "unwrap" and "interior_mut" flags on #[delegate] attribute can not be used together
+#[delegate(Shout, unwrap, interior_mut)]
+pub struct Both(Option<RefCell<Cat>>);
+
+#[derive(Delegate)]
+#[delegate(Shout, interior_mut)] //~ ERROR "interior_mut" flag on #[delegate] attribute can only be specified for struct fields
+pub enum Animal {
+    Cat(RefCell<Cat>),
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, delegate_to_methods};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+#[delegatable_trait]
+pub struct NotATrait; //~ ERROR expected `trait`
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+pub struct BoxedCat(Box<Cat>);
+
+#[delegate_to_methods]
+#[delegate(Shout, target_ref = "inner")]
+#[allow(dead_code)] //~ ERROR All attributes must be "delegate"
+impl BoxedCat {
+    fn inner(&self) -> &Cat {
+        &self.0
+    }
+}
+
+pub fn main() {}
@@ -0,0 +1,27 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+use std::sync::Mutex;
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, rwlock)]
+pub struct NotRwLock(Mutex<Cat>); //~ ERROR "rwlock" flag on #[delegate] attribute requires the target field to be an `RwLock`
+
+#[derive(Delegate)]
+#[delegate(Shout, poisoned = "oops")] //~ ERROR "poisoned" value on #[delegate] attribute can only be specified with the "lock" or "rwlock" flag
+pub struct NoLock(Cat);
+
+fn main() {}
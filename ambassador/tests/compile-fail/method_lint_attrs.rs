@@ -0,0 +1,42 @@
+#![deny(unused_must_use, deprecated)]
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    #[must_use]
+    fn shout(&self, input: &str) -> String;
+    #[deprecated(note = "use shout")]
+    fn yell(&self) -> String {
+        String::new()
+    }
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+// The forwarders don't repeat the attributes, the lints still apply through the trait
+// and calling the deprecated method from its own forwarder is allowed
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub struct WrappedCat(Cat);
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub enum Animals {
+    Cat(Cat),
+}
+
+pub fn main() {
+    let cat = WrappedCat(Cat);
+    cat.shout("BAR"); //~ ERROR unused return value of `Shout::shout` that must be used
+    let _ = cat.yell(); //~ ERROR use of deprecated method `Shout::yell`: use shout
+    let animal = Animals::Cat(Cat);
+    animal.shout("BAR"); //~ ERROR unused return value of `Shout::shout` that must be used
+}
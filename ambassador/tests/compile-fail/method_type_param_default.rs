@@ -0,0 +1,27 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+// Defaults of a method's type parameters are rejected by the compiler,
+// the delegated impl doesn't add any errors of its own
+#[delegatable_trait]
+pub trait Convert {
+    fn convert<T = u8>(&self, value: u8) -> T //~ ERROR defaults for generic parameters are not allowed here
+    //~| WARNING this was previously accepted by the compiler
+    where
+        T: From<u8>;
+}
+
+pub struct Cat;
+
+impl Convert for Cat {
+    fn convert<T: From<u8>>(&self, value: u8) -> T {
+        T::from(value)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Convert)]
+pub struct Wrapper(Cat);
+
+fn main() {}
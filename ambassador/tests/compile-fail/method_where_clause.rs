@@ -0,0 +1,32 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Duplicate {
+    fn duplicate<T>(&self, t: T) -> (T, T)
+    where
+        T: Clone;
+}
+
+pub struct Cloner;
+
+impl Duplicate for Cloner {
+    fn duplicate<T>(&self, t: T) -> (T, T)
+    where
+        T: Clone,
+    {
+        (t.clone(), t)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Duplicate)]
+pub struct Wrapper(Cloner);
+
+pub struct NotClone;
+
+pub fn main() {
+    // The where clause of the method still applies to the delegated method
+    Wrapper(Cloner).duplicate(NotClone); //~ ERROR the trait bound `NotClone: Clone` is not satisfied
+}
@@ -0,0 +1,35 @@
+extern crate ambassador;
+use ambassador::{delegatable_trait, delegate_to_methods, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self) -> String {
+        "meow!".to_owned()
+    }
+}
+
+#[derive(Delegate)]
+#[delegate()] //~ ERROR expected a trait name, e.g. #[delegate(MyTrait)]
+pub struct Wrapped(Cat);
+
+#[derive(Delegate)]
+#[delegate] //~ ERROR expected a trait name, e.g. #[delegate(MyTrait)]
+pub struct Other(Cat);
+
+pub struct Boxed(Box<Cat>);
+
+#[delegate_to_methods]
+#[delegate()] //~ ERROR expected a trait name, e.g. #[delegate(MyTrait)]
+impl Boxed {
+    fn inner(&self) -> &Cat {
+        &self.0
+    }
+}
+
+pub fn main() {}
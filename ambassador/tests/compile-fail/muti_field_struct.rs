@@ -25,13 +25,17 @@ impl Shout for Dog {
 
 #[derive(Delegate)]
 #[delegate(Shout)]
-//~^ Error "target" value on #[delegate] attribute has to be specified for structs with multiple fields
+//~^ Error "target" value on #[delegate] attribute has to be specified for structs with multiple fields, available fields of `WrappedAnimals` are: `0`, `1`
 #[delegate(Shout target = "1")] //~ Error expected `,`
 #[delegate(Shout, target "1")] //~ Error expected `=`
 #[delegate(Shout, not_target = "1")] //~ Error invalid key for a delegate attribute
 #[delegate(Shout, target = "0+1")] //~ Error unexpected token
-#[delegate(Shout, target = "2")] //~ Error Unknown field specified as "target" value in #[delegate] attribute
-#[delegate(Shout, target = "foo")] //~ Error Unknown field specified as "target" value in #[delegate] attribute
+#[delegate(Shout, target = "2")] //~ Error field index 2 out of range (struct `WrappedAnimals` has 2 field(s))
+#[delegate(Shout, target = 7)] //~ Error field index 7 out of range (struct `WrappedAnimals` has 2 field(s))
+#[delegate(Shout, target = "foo")] //~ Error Unknown field specified as "target" value in #[delegate] attribute, available fields of `WrappedAnimals` are: `0`, `1`
+#[delegate(Shout, target = "*")] //~ Error target = "*" can only be used for structs with a single field, use "*field" to select one
+#[delegate(Shout, target = "0", not_a_flag)] //~ Error invalid flag for a delegate attribute
+#[delegate(Shout, target = "0", per_variant)] //~ Error "per_variant" flag on #[delegate] attribute can only be specified for enums
 pub struct WrappedAnimals(Cat, Dog);
 
 pub fn main() {}
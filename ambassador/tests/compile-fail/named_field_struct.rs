@@ -0,0 +1,28 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+//~^ ERROR "target" value on #[delegate] attribute has to be specified for structs with multiple fields, available fields of `Pair` are: `left`, `right`
+#[delegate(Shout, target = "middle")] //~ ERROR Unknown field specified as "target" value in #[delegate] attribute, available fields of `Pair` are: `left`, `right`
+#[delegate(Shout, target = "0")] //~ ERROR Unknown field specified as "target" value in #[delegate] attribute, available fields of `Pair` are: `left`, `right`
+pub struct Pair {
+    left: Cat,
+    right: Cat,
+}
+
+pub fn main() {}
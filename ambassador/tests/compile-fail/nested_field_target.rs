@@ -0,0 +1,33 @@
+extern crate ambassador;
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self) -> String {
+        "meow!".to_owned()
+    }
+}
+
+pub struct Wrapper {
+    pub inner: Cat,
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "wraper.inner")] //~ ERROR Unknown field specified as "target" value in #[delegate] attribute, available fields of `Owner` are: `wrapper`
+pub struct Owner {
+    wrapper: Wrapper,
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "wrapper.iner")] //~ ERROR no field `iner` on type `Wrapper`
+pub struct Other {
+    wrapper: Wrapper,
+}
+
+pub fn main() {}
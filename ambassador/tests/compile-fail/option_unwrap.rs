@@ -0,0 +1,27 @@
+extern crate ambassador;
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self) -> String {
+        "meow!".to_owned()
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, unwrap)]
+pub struct NotOptional(Cat); //~ ERROR "unwrap" flag on #[delegate] attribute requires the target field to be an `Option`
+
+#[derive(Delegate)]
+#[delegate(Shout, unwrap)] //~ ERROR "unwrap" flag on #[delegate] attribute can only be specified for struct fields
+pub enum Animals {
+    Cat(Option<Cat>),
+}
+
+pub fn main() {}
@@ -0,0 +1,35 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "self", target_ref = "0")]
+//~^ ERROR target = "self" can not be combined with other "target" values
+#[delegate(Shout, target_ref = "self")] //~ ERROR "self" can only be used as "target" value
+#[delegate(Shout, target_ref = "0", target_ref = "1")]
+//~^ ERROR "target_ref" value for delegate attribute can only be specified once
+#[delegate(Shout, target_mut = "2")]
+//~^ ERROR field index 2 out of range (struct `Cats` has 2 field(s))
+pub struct Cats(Cat, Cat);
+
+#[derive(Delegate)]
+#[delegate(Shout, target_ref = "self.0")]
+//~^ ERROR "target" values on #[delegate] attribute can not be specified for enums
+pub enum Animals {
+    Cat(Cat),
+}
+
+pub fn main() {}
@@ -0,0 +1,36 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait] //~ ERROR method `rename` of trait `Animal` doesn't take `self` or `&self` and has no default implementation, so it can't be skipped
+pub trait Animal {
+    fn name(&self) -> String;
+    fn rename(&mut self, name: &str);
+}
+
+pub struct Cat;
+
+impl Animal for Cat {
+    fn name(&self) -> String {
+        "Tom".to_string()
+    }
+    fn rename(&mut self, _name: &str) {}
+}
+
+#[derive(Delegate)]
+#[delegate(Animal, receivers = "value, ref")] //~ ERROR not all trait items implemented, missing: `rename`
+pub struct Shared(Cat);
+
+#[derive(Delegate)]
+#[delegate(Animal, receivers = "ref, mut")] //~ ERROR unknown receiver `mut` in "receivers" value on #[delegate] attribute, expected `value`, `ref` or `ref_mut`
+pub struct Unknown(Cat);
+
+#[derive(Delegate)]
+#[delegate(Animal, receivers = "")] //~ ERROR "receivers" value on #[delegate] attribute has to list at least one of `value`, `ref` or `ref_mut`
+pub struct Empty(Cat);
+
+#[derive(Delegate)]
+#[delegate(Animal, ref_only, receivers = "ref")] //~ ERROR "ref_only" flag and "receivers" value on #[delegate] attribute can only be specified once and not together
+pub struct Both(Cat);
+
+fn main() {}
@@ -0,0 +1,25 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+use std::sync::Arc;
+
+#[delegatable_trait] //~ ERROR method `rename` of trait `Animal` doesn't take `&self` and has no default implementation
+pub trait Animal {
+    fn name(&self) -> String;
+    fn rename(&mut self, name: &str);
+}
+
+pub struct Cat;
+
+impl Animal for Cat {
+    fn name(&self) -> String {
+        "Tom".to_string()
+    }
+    fn rename(&mut self, _name: &str) {}
+}
+
+#[derive(Delegate)]
+#[delegate(Animal, target = "*", ref_only)] //~ ERROR not all trait items implemented, missing: `rename`
+pub struct SharedCat(Arc<Cat>);
+
+fn main() {}
@@ -0,0 +1,9 @@
+extern crate ambassador;
+use ambassador::delegatable_trait;
+
+#[delegatable_trait(name = "Other")] //~ ERROR expected `as`
+pub trait Shout {
+    fn shout(&self) -> String;
+}
+
+pub fn main() {}
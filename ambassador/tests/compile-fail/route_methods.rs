@@ -0,0 +1,38 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait] //~ ERROR method `sound` of trait `Animal` has no route and no default implementation
+pub trait Animal {
+    fn name(&self) -> String;
+    fn sound(&self) -> String;
+    fn greet(&self) -> String {
+        format!("{} says {}", self.name(), self.sound())
+    }
+}
+
+pub struct Name;
+
+impl Animal for Name {
+    fn name(&self) -> String {
+        "Tom".to_owned()
+    }
+    fn sound(&self) -> String {
+        "...".to_owned()
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Animal, route(name = "name"))] //~ ERROR not all trait items implemented, missing: `sound`
+pub struct Cat {
+    name: Name,
+    age: u32,
+}
+
+#[derive(Delegate)]
+#[delegate(Animal, route(name = "name"))] //~ ERROR "route" on #[delegate] attribute can only be specified for structs
+pub enum Animals {
+    Cat(Name),
+}
+
+pub fn main() {}
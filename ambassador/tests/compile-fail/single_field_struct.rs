@@ -0,0 +1,24 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "outer")] //~ Error Unknown field specified as "target" value in #[delegate] attribute, available fields of `WrappedCat` are: `inner`
+pub struct WrappedCat {
+    inner: Cat,
+}
+
+pub fn main() {}
@@ -0,0 +1,38 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target_type = "Dog")]
+//~^ ERROR No field of type `Dog` specified as "target_type" value in #[delegate] attribute, available fields of `Cats` are: `first`, `second`, `age`
+#[delegate(Shout, target_type = "Cat")]
+//~^ ERROR Multiple fields of type `Cat` specified as "target_type" value in #[delegate] attribute, use a "target" value to select one of: `first`, `second`
+#[delegate(Shout, target_type = "u32", target = "first")]
+//~^ ERROR "target" and "target_type" values on #[delegate] attribute can not be used together
+pub struct Cats {
+    first: Cat,
+    second: Cat,
+    age: u32,
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target_type = "Cat")]
+//~^ ERROR "target_type" value on #[delegate] attribute can only be specified for structs
+pub enum Animals {
+    Cat(Cat),
+}
+
+pub fn main() {}
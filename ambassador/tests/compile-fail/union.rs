@@ -0,0 +1,17 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub union Animal { //~ ERROR ambassador currently only supports #[derive(Delegate)] for
+    legs: u32,
+    wings: u64,
+}
+
+pub fn main() {}
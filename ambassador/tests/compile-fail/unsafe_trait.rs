@@ -0,0 +1,37 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub unsafe trait Zeroable {
+    fn is_zero(&self) -> bool;
+}
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+unsafe impl Zeroable for Cat {
+    fn is_zero(&self) -> bool {
+        false
+    }
+}
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Zeroable)] //~ ERROR the trait `Zeroable` requires an `unsafe impl` declaration
+pub struct MissingUnsafe(Cat);
+
+#[derive(Delegate)]
+#[delegate(Shout, unsafe)] //~ ERROR implementing the trait `Shout` is not unsafe
+pub struct NotUnsafe(Cat);
+
+pub fn main() {}
@@ -0,0 +1,23 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+use std::rc::Rc;
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+impl Shout for Rc<String> {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {}!", input, self)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, where = "Self: Send")]
+pub struct Wrapper<T>(T);
+
+pub fn main() {
+    Wrapper(Rc::new("meow".to_owned())).shout("BAR"); //~ ERROR `Wrapper<Rc<String>>` cannot be sent between threads safely
+}
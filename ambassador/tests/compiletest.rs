@@ -3,11 +3,16 @@ extern crate compiletest_rs as compiletest;
 use std::path::PathBuf;
 
 fn run_mode(mode: &'static str) {
-    let mut config = compiletest::Config::default();
+    run_dir(mode, mode);
+}
 
-    config.mode = mode.parse().expect("Invalid mode");
-    config.target_rustcflags = Some("-L ../target/debug/deps".to_owned());
-    config.src_base = PathBuf::from(format!("tests/{}", mode));
+fn run_dir(mode: &'static str, dir: &'static str) {
+    let mut config = compiletest::Config {
+        mode: mode.parse().expect("Invalid mode"),
+        target_rustcflags: Some("-L ../target/debug/deps".to_owned()),
+        src_base: PathBuf::from(format!("tests/{}", dir)),
+        ..Default::default()
+    };
     config.link_deps(); // Populate config.target_rustcflags with dependencies on the path
 
     config.clean_rmeta(); // If your tests import the parent crate, this helps with E0464
@@ -24,3 +29,10 @@ fn run_pass() {
 fn compile_fail() {
     run_mode("compile-fail");
 }
+
+// Tests of unstable language features, run with `cargo +nightly test --features nightly`
+#[cfg(feature = "nightly")]
+#[test]
+fn run_pass_nightly() {
+    run_dir("run-pass", "nightly");
+}
@@ -0,0 +1,66 @@
+#![feature(const_trait_impl)]
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, delegate_to_methods, Delegate};
+
+#[delegatable_trait]
+pub const trait Legs {
+    fn legs(&self) -> u32;
+    fn with_legs(&mut self, legs: u32);
+}
+
+pub struct Cat(u32);
+
+impl const Legs for Cat {
+    fn legs(&self) -> u32 {
+        self.0
+    }
+    fn with_legs(&mut self, legs: u32) {
+        self.0 = legs;
+    }
+}
+
+// The `const` flag makes the generated impl `impl const Legs for Wrapper`
+#[derive(Delegate)]
+#[delegate(Legs, const)]
+pub struct Wrapper(Cat);
+
+#[derive(Delegate)]
+#[delegate(Legs, target = "cat", const)]
+pub struct Owner {
+    name: &'static str,
+    cat: Cat,
+}
+
+pub struct Shelter(Cat);
+
+#[delegate_to_methods]
+#[delegate(Legs, target_ref = "cat", target_mut = "cat_mut", const)]
+impl Shelter {
+    const fn cat(&self) -> &Cat {
+        &self.0
+    }
+    const fn cat_mut(&mut self) -> &mut Cat {
+        &mut self.0
+    }
+}
+
+const fn three_legged<T: [const] Legs>(animal: &mut T) -> u32 {
+    animal.with_legs(3);
+    animal.legs()
+}
+
+const WRAPPED: u32 = Wrapper(Cat(4)).legs();
+const OWNED: u32 = Owner {
+    name: "Jon",
+    cat: Cat(4),
+}
+.legs();
+const SHELTERED: u32 = three_legged(&mut Shelter(Cat(4)));
+
+pub fn main() {
+    assert_eq!(WRAPPED, 4);
+    assert_eq!(OWNED, 4);
+    assert_eq!(SHELTERED, 3);
+    assert_eq!(Owner { name: "Jon", cat: Cat(4) }.name, "Jon");
+}
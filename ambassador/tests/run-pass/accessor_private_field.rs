@@ -0,0 +1,56 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Counter {
+    fn count(&self) -> u32;
+    fn increment(&mut self);
+}
+
+pub struct Clicks(u32);
+
+impl Counter for Clicks {
+    fn count(&self) -> u32 {
+        self.0
+    }
+    fn increment(&mut self) {
+        self.0 += 1;
+    }
+}
+
+mod handle {
+    use super::Clicks;
+
+    // `clicks` is private, so it can only be reached through the accessors
+    pub struct Handle {
+        clicks: Clicks,
+    }
+
+    impl Handle {
+        pub fn new() -> Self {
+            Handle { clicks: Clicks(0) }
+        }
+        pub fn get(&self) -> &Clicks {
+            &self.clicks
+        }
+        pub fn get_mut(&mut self) -> &mut Clicks {
+            &mut self.clicks
+        }
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Counter, target_ref = "self.handle.get()", target_mut = "self.handle.get_mut()")]
+pub struct Button {
+    handle: handle::Handle,
+}
+
+pub fn main() {
+    let mut button = Button {
+        handle: handle::Handle::new(),
+    };
+    button.increment();
+    button.increment();
+    assert_eq!(button.count(), 2);
+}
@@ -0,0 +1,92 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, delegatable_trait_remote, Delegate};
+
+#[delegatable_trait_remote]
+pub trait From<T> {
+    fn from(t: T) -> Self;
+}
+
+#[delegatable_trait]
+pub trait Animal {
+    fn new(name: &str) -> Self;
+    fn kind() -> &'static str;
+    fn name(&self) -> String;
+}
+
+pub struct Cat {
+    name: String,
+}
+
+impl Animal for Cat {
+    fn new(name: &str) -> Self {
+        Cat {
+            name: name.to_string(),
+        }
+    }
+    fn kind() -> &'static str {
+        "cat"
+    }
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl From<&str> for Cat {
+    fn from(name: &str) -> Self {
+        Cat::new(name)
+    }
+}
+
+// `from_inner` converts the target's `Self` into the wrapper
+#[derive(Delegate)]
+#[delegate(From<&'a str>, generics = "'a", from_inner = "Self")]
+#[delegate(Animal, from_inner = "Self")]
+pub struct Pet(Cat);
+
+#[derive(Delegate)]
+#[delegate(From<&'a str>, target = "cat", generics = "'a", from_inner = "|cat| Named { cat }")]
+pub struct Named {
+    cat: Cat,
+}
+
+pub struct Robot;
+
+impl Robot {
+    fn new(_name: &str) -> Self {
+        Robot
+    }
+    fn kind() -> &'static str {
+        "robot"
+    }
+    fn name(&self) -> String {
+        "R2".to_string()
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Animal, target = "self")]
+pub struct RobotWrapper(Robot);
+
+impl RobotWrapper {
+    fn new(name: &str) -> Self {
+        RobotWrapper(Robot::new(name))
+    }
+    fn kind() -> &'static str {
+        Robot::kind()
+    }
+    fn name(&self) -> String {
+        self.0.name()
+    }
+}
+
+pub fn main() {
+    let pet: Pet = "Tom".into();
+    assert_eq!(pet.name(), "Tom");
+    assert_eq!(<Pet as Animal>::new("Felix").name(), "Felix");
+    assert_eq!(<Pet as Animal>::kind(), "cat");
+    let named = Named::from("Garfield");
+    assert_eq!(named.cat.name(), "Garfield");
+    assert_eq!(<RobotWrapper as Animal>::kind(), "robot");
+    assert_eq!(<RobotWrapper as Animal>::new("C3").name(), "R2");
+}
@@ -0,0 +1,85 @@
+// edition:2018
+
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+#[delegatable_trait]
+pub trait Shout {
+    async fn shout(&self, input: &str) -> String;
+}
+
+// The form `#[async_trait]` expands to, it has to be applied before `#[delegatable_trait]`
+#[delegatable_trait]
+pub trait BoxedShout {
+    fn boxed_shout<'a>(&'a self, input: &'a str) -> Pin<Box<dyn Future<Output = String> + Send + 'a>>;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    async fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+impl BoxedShout for Cat {
+    fn boxed_shout<'a>(&'a self, input: &'a str) -> Pin<Box<dyn Future<Output = String> + Send + 'a>> {
+        Box::pin(async move { format!("{} - meow!", input) })
+    }
+}
+
+pub struct Dog;
+
+impl Shout for Dog {
+    async fn shout(&self, input: &str) -> String {
+        format!("{} - wuff!", input)
+    }
+}
+
+impl BoxedShout for Dog {
+    fn boxed_shout<'a>(&'a self, input: &'a str) -> Pin<Box<dyn Future<Output = String> + Send + 'a>> {
+        Box::pin(async move { format!("{} - wuff!", input) })
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+#[delegate(BoxedShout)]
+pub struct WrappedCat(Cat);
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+#[delegate(BoxedShout)]
+pub enum Animals {
+    Cat(Cat),
+    Dog(Dog),
+}
+
+// The futures above never wait, so they complete on the first poll
+fn block_on<F: Future>(future: F) -> F::Output {
+    fn noop_raw_waker() -> RawWaker {
+        fn clone(_: *const ()) -> RawWaker {
+            noop_raw_waker()
+        }
+        fn noop(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+        RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+    let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+    let mut future = Box::pin(future);
+    match future.as_mut().poll(&mut Context::from_waker(&waker)) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("future did not complete"),
+    }
+}
+
+pub fn main() {
+    assert_eq!(block_on(WrappedCat(Cat).shout("BAR")), "BAR - meow!");
+    assert_eq!(block_on(WrappedCat(Cat).boxed_shout("BAR")), "BAR - meow!");
+    assert_eq!(block_on(Animals::Dog(Dog).shout("BAR")), "BAR - wuff!");
+    assert_eq!(block_on(Animals::Cat(Cat).boxed_shout("BAR")), "BAR - meow!");
+}
@@ -0,0 +1,46 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+pub struct Dog;
+
+impl Shout for Dog {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - wuff!", input)
+    }
+}
+
+// The impl also requires the target to be `Send + Sync`: `where T: Shout + Send + Sync`
+#[derive(Delegate)]
+#[delegate(Shout, auto_bounds = "Send + Sync")]
+pub struct Wrapper<T>(T);
+
+#[derive(Delegate)]
+#[delegate(Shout, auto_bounds = "Send + 'static")]
+pub enum Either<A, B> {
+    Left(A),
+    Right(B),
+}
+
+fn shout_from_thread<T: Shout + Send + 'static>(value: T) -> String {
+    std::thread::spawn(move || value.shout("BAR")).join().unwrap()
+}
+
+pub fn main() {
+    assert_eq!(Wrapper(Cat).shout("BAR"), "BAR - meow!");
+    let either: Either<Cat, Dog> = Either::Right(Dog);
+    assert_eq!(shout_from_thread(either), "BAR - wuff!");
+}
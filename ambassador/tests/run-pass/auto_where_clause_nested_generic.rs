@@ -0,0 +1,48 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+pub struct Loud<T>(T);
+
+impl<T: Shout> Shout for Loud<T> {
+    fn shout(&self, input: &str) -> String {
+        self.0.shout(input).to_uppercase()
+    }
+}
+
+pub struct Silent<T>(T);
+
+impl<T> Shout for Silent<T> {
+    fn shout(&self, _input: &str) -> String {
+        String::new()
+    }
+}
+
+// No `where` key needed, the automatic where clauses are `Loud<T>: Shout` and `Silent<T>: Shout`
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub struct WrappedLoud<T>(Loud<T>);
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub struct WrappedSilent<T>(Silent<T>);
+
+pub struct NotAnAnimal;
+
+pub fn main() {
+    assert_eq!(WrappedLoud(Loud(Cat)).shout("bar"), "BAR - MEOW!");
+    assert_eq!(WrappedSilent(Silent(NotAnAnimal)).shout("bar"), "");
+}
@@ -0,0 +1,62 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, delegate_to_methods, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+pub struct Dog;
+
+impl Shout for Dog {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - wuff!", input)
+    }
+}
+
+// The feature is never enabled, so the impls gated on it would conflict if they were generated
+#[derive(Delegate)]
+#[delegate(Shout, target = "cat", cfg = "not(feature = \"dogs\")")]
+#[delegate(Shout, target = "dog", cfg = "feature = \"dogs\"")]
+pub struct Pets {
+    cat: Cat,
+    dog: Dog,
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, cfg = "not(feature = \"dogs\")")]
+#[delegate(Shout, cfg = "feature = \"dogs\"", per_variant)]
+pub enum Animals {
+    Cat(Cat),
+    Dog(Dog),
+}
+
+pub struct BoxedCat(Box<Cat>);
+
+#[delegate_to_methods]
+#[delegate(Shout, target_ref = "inner", cfg = "all()")]
+#[delegate(Shout, target_ref = "other", cfg = "any()")]
+impl BoxedCat {
+    fn inner(&self) -> &Cat {
+        &self.0
+    }
+    fn other(&self) -> &Dog {
+        &Dog
+    }
+}
+
+pub fn main() {
+    let pets = Pets { cat: Cat, dog: Dog };
+    assert_eq!(pets.shout("BAR"), "BAR - meow!");
+    assert_eq!(Animals::Dog(Dog).shout("BAR"), "BAR - wuff!");
+    assert_eq!(BoxedCat(Box::new(Cat)).shout("BAR"), "BAR - meow!");
+}
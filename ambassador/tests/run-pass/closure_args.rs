@@ -0,0 +1,55 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Visit<T> {
+    fn each<F: FnMut(&T)>(&self, f: F);
+    fn each_mut(&mut self, f: impl FnMut(&mut T));
+    fn fold<A, F>(&self, init: A, f: F) -> A
+    where
+        F: Fn(A, &T) -> A;
+}
+
+pub struct Items(Vec<u32>);
+
+impl Visit<u32> for Items {
+    fn each<F: FnMut(&u32)>(&self, f: F) {
+        self.0.iter().for_each(f)
+    }
+    fn each_mut(&mut self, f: impl FnMut(&mut u32)) {
+        self.0.iter_mut().for_each(f)
+    }
+    fn fold<A, F>(&self, init: A, f: F) -> A
+    where
+        F: Fn(A, &u32) -> A,
+    {
+        self.0.iter().fold(init, f)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Visit<u32>)]
+pub struct Wrapper(Items);
+
+#[derive(Delegate)]
+#[delegate(Visit<T>, generics = "T")]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+pub fn main() {
+    let mut wrapper = Wrapper(Items(vec![1, 2, 3]));
+    wrapper.each_mut(|item| *item *= 2);
+    let mut seen = Vec::new();
+    wrapper.each(|item| seen.push(*item));
+    assert_eq!(seen, [2, 4, 6]);
+    assert_eq!(wrapper.fold(0, |sum, item| sum + item), 12);
+
+    let either: Either<Items, Wrapper> = Either::Right(wrapper);
+    let mut count = 0;
+    either.each(|_| count += 1);
+    assert_eq!(count, 3);
+    assert_eq!(either.fold(String::new(), |s, item| s + &item.to_string()), "246");
+}
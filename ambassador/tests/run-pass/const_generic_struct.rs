@@ -0,0 +1,67 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Count {
+    fn count(&self) -> usize;
+}
+
+pub struct Cat;
+
+impl<const N: usize> Count for [Cat; N] {
+    fn count(&self) -> usize {
+        N
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Count)]
+pub struct Cats<const N: usize>([Cat; N]);
+
+#[derive(Delegate)]
+#[delegate(Count, target = "cats")]
+pub struct NamedCats<T, const N: usize> {
+    name: T,
+    cats: [Cat; N],
+}
+
+#[derive(Delegate)]
+#[delegate(Count)]
+pub enum Litter<const N: usize, const M: usize> {
+    Small([Cat; N]),
+    Large([Cat; M]),
+}
+
+#[delegatable_trait]
+pub trait Scaled<const K: usize> {
+    fn scaled(&self) -> usize;
+}
+
+impl<const N: usize, const K: usize> Scaled<K> for [Cat; N] {
+    fn scaled(&self) -> usize {
+        N * K
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Scaled<K>, generics = "const K: usize")]
+pub struct ScaledCats<const N: usize>([Cat; N]);
+
+#[derive(Delegate)]
+#[delegate(Scaled<K>, generics = "const K: usize")] // impl<T, const K: usize>, const parameters come last
+pub struct Wrapped<T>(T);
+
+pub fn main() {
+    assert_eq!(Cats([Cat, Cat]).count(), 2);
+    let named = NamedCats {
+        name: "cats",
+        cats: [Cat, Cat, Cat],
+    };
+    assert_eq!(named.name, "cats");
+    assert_eq!(named.count(), 3);
+    assert_eq!(Litter::Small::<1, 4>([Cat]).count(), 1);
+    assert_eq!(Litter::Large::<1, 4>([Cat, Cat, Cat, Cat]).count(), 4);
+    assert_eq!(Scaled::<3>::scaled(&ScaledCats([Cat, Cat])), 6);
+    assert_eq!(Scaled::<2>::scaled(&Wrapped([Cat, Cat])), 4);
+}
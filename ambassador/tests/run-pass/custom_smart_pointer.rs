@@ -0,0 +1,82 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+use std::ops::{Deref, DerefMut};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+    fn rename(&mut self, name: &str);
+}
+
+pub struct Cat(String);
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {} meows!", input, self.0)
+    }
+    fn rename(&mut self, name: &str) {
+        self.0 = name.to_owned();
+    }
+}
+
+pub struct Counted<T> {
+    value: T,
+    derefs: std::cell::Cell<u32>,
+}
+
+// Inherent methods of the pointer with the trait's method names aren't called by the delegated impl
+impl<T> Counted<T> {
+    pub fn shout(&self) -> u32 {
+        self.derefs.get()
+    }
+    pub fn rename(&mut self) {
+        panic!("the pointer's own method was called")
+    }
+}
+
+impl<T> Deref for Counted<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.derefs.set(self.derefs.get() + 1);
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Counted<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.derefs.set(self.derefs.get() + 1);
+        &mut self.value
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "*")]
+pub struct Shared(Counted<Cat>);
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "*pointer")]
+pub struct Named {
+    pointer: Counted<Cat>,
+}
+
+pub fn main() {
+    let mut shared = Shared(Counted {
+        value: Cat("Tom".to_string()),
+        derefs: Default::default(),
+    });
+    shared.rename("Felix");
+    assert_eq!(shared.shout("BAR"), "BAR - Felix meows!");
+    assert_eq!(shared.0.shout(), 2);
+
+    let mut named = Named {
+        pointer: Counted {
+            value: Cat("Tom".to_string()),
+            derefs: Default::default(),
+        },
+    };
+    named.rename("Felix");
+    assert_eq!(named.shout("BAR"), "BAR - Felix meows!");
+    assert_eq!(named.pointer.shout(), 2);
+}
@@ -0,0 +1,53 @@
+// edition:2018
+extern crate ambassador;
+
+use ambassador::{delegatable_trait_remote, Delegate};
+
+#[delegatable_trait_remote]
+pub trait Default {
+    fn default() -> Self;
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    retries: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { retries: 3 }
+    }
+}
+
+// `Config`'s default is used, the other fields get their own default
+#[derive(Delegate, Debug, PartialEq)]
+#[delegate(Default, target = "config", default_fields)]
+pub struct Builder {
+    name: String,
+    config: Config,
+    verbose: bool,
+}
+
+#[derive(Delegate, Debug, PartialEq)]
+#[delegate(Default, target = "1", default_fields)]
+pub struct Tagged<T>(Vec<T>, Config);
+
+#[derive(Delegate, Debug, PartialEq)]
+#[delegate(Default, default_fields)]
+pub struct Wrapper(Config);
+
+pub fn main() {
+    assert_eq!(
+        Builder::default(),
+        Builder {
+            name: String::new(),
+            config: Config { retries: 3 },
+            verbose: false,
+        }
+    );
+    assert_eq!(
+        Tagged::<u8>::default(),
+        Tagged(Vec::new(), Config { retries: 3 })
+    );
+    assert_eq!(Wrapper::default(), Wrapper(Config { retries: 3 }));
+}
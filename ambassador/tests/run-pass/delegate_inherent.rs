@@ -0,0 +1,55 @@
+extern crate ambassador;
+
+use ambassador::delegate_inherent;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+pub struct Names(Vec<String>);
+
+#[delegate_inherent(target = "0")]
+impl Names {
+    pub fn len(&self) -> usize;
+    pub fn is_empty(&self) -> bool;
+    pub fn push(&mut self, value: String);
+    pub fn contains(&self, x: &String) -> bool;
+    pub fn into_boxed_slice(self) -> Box<[String]>;
+}
+
+pub struct Index<K, V> {
+    map: HashMap<K, V>,
+    hits: u32,
+}
+
+#[delegate_inherent(target = "map")]
+impl<K: Eq + Hash, V> Index<K, V> {
+    /// Inserts a value, returning the previous one
+    pub fn insert(&mut self, key: K, value: V) -> Option<V>;
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: std::borrow::Borrow<Q>,
+        Q: Hash + Eq + ?Sized;
+}
+
+impl<K: Eq + Hash, V> Index<K, V> {
+    fn new() -> Self {
+        Index {
+            map: HashMap::new(),
+            hits: 0,
+        }
+    }
+}
+
+pub fn main() {
+    let mut names = Names(Vec::new());
+    assert!(names.is_empty());
+    names.push("Tom".to_string());
+    assert_eq!(names.len(), 1);
+    assert!(names.contains(&"Tom".to_string()));
+    assert_eq!(names.into_boxed_slice().len(), 1);
+
+    let mut index = Index::new();
+    assert_eq!(index.insert("cat", 1), None);
+    assert_eq!(index.insert("cat", 2), Some(1));
+    assert_eq!(index.get("cat"), Some(&2));
+    assert_eq!(index.hits, 0);
+}
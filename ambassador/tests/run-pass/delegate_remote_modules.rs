@@ -0,0 +1,59 @@
+extern crate ambassador;
+
+// Simulates a crate that defines (and registers) a trait
+#[macro_use]
+mod crate_a {
+    use ambassador::delegatable_trait;
+
+    #[delegatable_trait]
+    pub trait Shout {
+        fn shout(&self, input: &str) -> String;
+    }
+
+    pub struct Cat;
+
+    impl Shout for Cat {
+        fn shout(&self, input: &str) -> String {
+            format!("{} - meow!", input)
+        }
+    }
+}
+
+// Simulates a crate that defines types without knowing about the trait
+mod crate_b {
+    pub struct Wrapper<T> {
+        pub inner: T,
+        pub id: u32,
+    }
+
+    pub enum Either<A, B> {
+        Left(A),
+        Right(B),
+    }
+}
+
+use ambassador::delegate_remote;
+use crate_a::{Cat, Shout};
+use crate_b::{Either, Wrapper};
+
+#[delegate_remote]
+#[delegate(Shout, target = "inner")]
+struct Wrapper<T> {
+    inner: T,
+    id: u32,
+}
+
+#[delegate_remote]
+#[delegate(Shout)]
+enum Either<A, B> {
+    Left(A),
+    Right(B),
+}
+
+pub fn main() {
+    let wrapper = Wrapper { inner: Cat, id: 1 };
+    assert_eq!(wrapper.shout("BAR"), "BAR - meow!");
+    assert_eq!(wrapper.id, 1);
+    let either: Either<Cat, Wrapper<Cat>> = Either::Right(wrapper);
+    assert_eq!(either.shout("BAZ"), "BAZ - meow!");
+}
@@ -0,0 +1,65 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+use std::rc::Rc;
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+    fn rename(&mut self, name: &str);
+}
+
+#[delegatable_trait]
+pub trait Name {
+    const KIND: &'static str;
+    fn name(&self) -> String;
+}
+
+pub struct Cat {
+    name: String,
+}
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {} says meow!", input, self.name)
+    }
+
+    fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+}
+
+impl Name for Cat {
+    const KIND: &'static str = "cat";
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "*")]
+pub struct BoxedAnimal(Box<dyn Shout>);
+
+#[derive(Delegate)]
+#[delegate(Name, target = "*cat")]
+pub struct SharedCat {
+    id: u32,
+    cat: Rc<Cat>,
+}
+
+pub fn main() {
+    let mut animal = BoxedAnimal(Box::new(Cat {
+        name: "Tom".to_string(),
+    }));
+    animal.rename("Felix");
+    assert_eq!(animal.shout("BAR"), "BAR - Felix says meow!");
+
+    let shared = SharedCat {
+        id: 0,
+        cat: Rc::new(Cat {
+            name: "Tom".to_string(),
+        }),
+    };
+    assert_eq!(shared.name(), "Tom");
+    assert_eq!(SharedCat::KIND, "cat");
+}
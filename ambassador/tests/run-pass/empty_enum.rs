@@ -0,0 +1,22 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+    fn rename(&mut self, name: &str);
+    fn into_name(self) -> String;
+}
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub enum Never {}
+
+fn shout_all<S: Shout>(animals: Vec<S>) -> Vec<String> {
+    animals.iter().map(|animal| animal.shout("BAR")).collect()
+}
+
+pub fn main() {
+    assert!(shout_all(Vec::<Never>::new()).is_empty());
+}
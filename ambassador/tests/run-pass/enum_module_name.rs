@@ -0,0 +1,38 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Describe<T> {
+    fn describe(&self, input: T) -> String;
+}
+
+pub struct Cat;
+
+impl<T: std::fmt::Debug> Describe<T> for Cat {
+    fn describe(&self, input: T) -> String {
+        format!("cat {:?}", input)
+    }
+}
+
+pub struct Dog;
+
+impl<T: std::fmt::Debug> Describe<T> for Dog {
+    fn describe(&self, input: T) -> String {
+        format!("dog {:?}", input)
+    }
+}
+
+// Both impls would otherwise be placed in a module named `ambassador_module_Describe_for_Animals`
+#[derive(Delegate)]
+#[delegate(Describe<i32>, module = "describe_i32")]
+#[delegate(Describe<bool>, module = "describe_bool")]
+pub enum Animals {
+    Cat(Cat),
+    Dog(Dog),
+}
+
+pub fn main() {
+    assert_eq!(Animals::Cat(Cat).describe(1), "cat 1");
+    assert_eq!(Animals::Dog(Dog).describe(true), "dog true");
+}
@@ -0,0 +1,62 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+use std::collections::VecDeque;
+
+#[delegatable_trait]
+pub trait Stack<T> {
+    fn push(&mut self, x: T);
+    fn top(&mut self) -> Option<&mut T>;
+    fn len(&self) -> usize;
+}
+
+impl<T> Stack<T> for Vec<T> {
+    fn push(&mut self, x: T) {
+        Vec::push(self, x)
+    }
+    fn top(&mut self) -> Option<&mut T> {
+        self.last_mut()
+    }
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+}
+
+impl<T> Stack<T> for VecDeque<T> {
+    fn push(&mut self, x: T) {
+        self.push_front(x)
+    }
+    fn top(&mut self) -> Option<&mut T> {
+        self.front_mut()
+    }
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Stack<T>)]
+pub enum Either<T> {
+    Vec(Vec<T>),
+    Deque(VecDeque<T>),
+}
+
+fn fill<S: Stack<u32>>(stack: &mut S) {
+    stack.push(1);
+    stack.push(2);
+    *stack.top().unwrap() += 10;
+}
+
+pub fn main() {
+    let mut vec = Either::Vec(Vec::new());
+    fill(&mut vec);
+    let mut deque = Either::Deque(VecDeque::new());
+    fill(&mut deque);
+    match (vec, deque) {
+        (Either::Vec(vec), Either::Deque(deque)) => {
+            assert_eq!(vec, vec![1, 12]);
+            assert_eq!(deque, vec![12, 1]);
+        }
+        _ => unreachable!(),
+    }
+}
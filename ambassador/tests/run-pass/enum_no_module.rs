@@ -0,0 +1,59 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+#[delegatable_trait]
+pub trait Legs {
+    const NUM_LEGS: usize;
+}
+
+pub fn main() {
+    // Types local to a function aren't visible to a generated module through `use super::*`
+    pub struct Cat;
+
+    impl Shout for Cat {
+        fn shout(&self, input: &str) -> String {
+            format!("{} - meow!", input)
+        }
+    }
+
+    impl Legs for Cat {
+        const NUM_LEGS: usize = 4;
+    }
+
+    pub struct Dog;
+
+    impl Shout for Dog {
+        fn shout(&self, input: &str) -> String {
+            format!("{} - wuff!", input)
+        }
+    }
+
+    impl Legs for Dog {
+        const NUM_LEGS: usize = 4;
+    }
+
+    #[derive(Delegate)]
+    #[delegate(Shout, no_module)]
+    #[delegate(Legs, no_module)]
+    pub enum Animals {
+        Cat(Cat),
+        Dog(Dog),
+    }
+
+    #[derive(Delegate)]
+    #[delegate(Shout, no_module)]
+    pub enum Pets {
+        Cat(Cat),
+        Other(Animals),
+    }
+
+    assert_eq!(Animals::Dog(Dog).shout("BAR"), "BAR - wuff!");
+    assert_eq!(Pets::Other(Animals::Cat(Cat)).shout("BAR"), "BAR - meow!");
+    assert_eq!(<Animals as Legs>::NUM_LEGS, 4);
+}
@@ -0,0 +1,52 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Wrap {
+    fn wrap(&self, inner: &str) -> String;
+    fn wrap_mut(&mut self, inner: String) -> String;
+    fn into_wrapped(self, inner: u32) -> String;
+}
+
+pub struct Cat;
+pub struct Dog;
+
+impl Wrap for Cat {
+    fn wrap(&self, inner: &str) -> String {
+        format!("[cat {}]", inner)
+    }
+    fn wrap_mut(&mut self, inner: String) -> String {
+        format!("[mut cat {}]", inner)
+    }
+    fn into_wrapped(self, inner: u32) -> String {
+        format!("[owned cat {}]", inner)
+    }
+}
+
+impl Wrap for Dog {
+    fn wrap(&self, inner: &str) -> String {
+        format!("[dog {}]", inner)
+    }
+    fn wrap_mut(&mut self, inner: String) -> String {
+        format!("[mut dog {}]", inner)
+    }
+    fn into_wrapped(self, inner: u32) -> String {
+        format!("[owned dog {}]", inner)
+    }
+}
+
+// The variant's field is bound under a name that can't clash with the method's parameters
+#[derive(Delegate)]
+#[delegate(Wrap)]
+pub enum Animal {
+    Cat(Cat),
+    Dog(Dog),
+}
+
+pub fn main() {
+    let mut cat = Animal::Cat(Cat);
+    assert_eq!(cat.wrap("a"), "[cat a]");
+    assert_eq!(cat.wrap_mut("b".to_string()), "[mut cat b]");
+    assert_eq!(Animal::Dog(Dog).into_wrapped(3), "[owned dog 3]");
+}
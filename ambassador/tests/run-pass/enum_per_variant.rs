@@ -0,0 +1,40 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+pub struct Dog;
+
+impl Shout for Dog {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - wuff!", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, per_variant)] // Bounds are `A: Shout, B: Shout` instead of `B: MatchShout<A>`
+pub enum Either<A, B> {
+    Left(A),
+    Right(B),
+}
+
+fn shout_twice<A: Shout, B: Shout>(animal: Either<A, B>) -> String {
+    animal.shout(&animal.shout("BAR"))
+}
+
+pub fn main() {
+    assert_eq!(shout_twice(Either::Left::<Cat, Dog>(Cat)), "BAR - meow! - meow!");
+    assert_eq!(shout_twice(Either::Right::<Cat, Dog>(Dog)), "BAR - wuff! - wuff!");
+}
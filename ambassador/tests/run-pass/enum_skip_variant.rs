@@ -0,0 +1,85 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+use std::panic;
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+    fn rename(&mut self, name: &str);
+    fn into_name(self) -> String
+    where
+        Self: Sized;
+}
+
+pub struct Cat(String);
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {} meows!", input, self.0)
+    }
+    fn rename(&mut self, name: &str) {
+        self.0 = name.to_owned();
+    }
+    fn into_name(self) -> String {
+        self.0
+    }
+}
+
+pub struct Dog(String);
+
+impl Shout for Dog {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {} wuffs!", input, self.0)
+    }
+    fn rename(&mut self, name: &str) {
+        self.0 = name.to_owned();
+    }
+    fn into_name(self) -> String {
+        self.0
+    }
+}
+
+// Marker variants don't delegate, calling a method on them is unreachable
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub enum Animals {
+    Cat(Cat),
+    #[delegate(skip)]
+    Nobody,
+    Dog(Dog),
+    #[delegate(skip)]
+    Unknown { age: u32 },
+}
+
+// The fallback has to fit every method, so it usually diverges
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub enum Pets {
+    Cat(Cat),
+    Dog(Dog),
+    #[delegate(skip = "panic!(\"no pet\")")]
+    Nobody,
+}
+
+pub fn main() {
+    let mut cat = Animals::Cat(Cat("Tom".to_string()));
+    cat.rename("Felix");
+    assert_eq!(cat.shout("BAR"), "BAR - Felix meows!");
+    assert_eq!(cat.into_name(), "Felix");
+    let dog = Animals::Dog(Dog("Rex".to_string()));
+    assert_eq!(dog.shout("BAR"), "BAR - Rex wuffs!");
+
+    let pet = Pets::Cat(Cat("Tom".to_string()));
+    assert_eq!(pet.shout("BAR"), "BAR - Tom meows!");
+
+    panic::set_hook(Box::new(|_| {}));
+    let nobody = panic::catch_unwind(|| Animals::Nobody.shout("BAR")).unwrap_err();
+    assert_eq!(
+        nobody.downcast_ref::<String>().unwrap(),
+        "internal error: entered unreachable code: enum variant `Animals::Nobody` is skipped by #[delegate(skip)]"
+    );
+    assert!(panic::catch_unwind(|| Animals::Unknown { age: 3 }.into_name()).is_err());
+    let no_pet = panic::catch_unwind(|| Pets::Nobody.shout("BAR")).unwrap_err();
+    assert_eq!(*no_pet.downcast_ref::<&str>().unwrap(), "no pet");
+}
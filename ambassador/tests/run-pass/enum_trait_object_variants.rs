@@ -0,0 +1,62 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+    fn rename(&mut self, name: &str);
+}
+
+pub struct Cat(String);
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {} says meow!", input, self.0)
+    }
+    fn rename(&mut self, name: &str) {
+        self.0 = name.to_string();
+    }
+}
+
+pub struct Dog;
+
+impl Shout for Dog {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - wuff!", input)
+    }
+    fn rename(&mut self, _name: &str) {}
+}
+
+// No `Box<dyn Shout>: Shout` bound is needed, the calls go to the trait objects
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub enum Animals {
+    Pet(Box<dyn Shout>),
+    Stray(Box<dyn Shout + Send>),
+}
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub enum Mixed<'a> {
+    Borrowed(&'a mut dyn Shout),
+    Owned(Box<dyn Shout>),
+    Known(Cat),
+}
+
+pub fn main() {
+    let mut pet = Animals::Pet(Box::new(Cat("Tom".to_string())));
+    pet.rename("Felix");
+    assert_eq!(pet.shout("BAR"), "BAR - Felix says meow!");
+    let stray = Animals::Stray(Box::new(Dog));
+    assert_eq!(stray.shout("BAR"), "BAR - wuff!");
+
+    let mut dog = Dog;
+    let borrowed = Mixed::Borrowed(&mut dog);
+    assert_eq!(borrowed.shout("BAR"), "BAR - wuff!");
+    let owned = Mixed::Owned(Box::new(Dog));
+    assert_eq!(owned.shout("BAR"), "BAR - wuff!");
+    let mut known = Mixed::Known(Cat("Tom".to_string()));
+    known.rename("Kitty");
+    assert_eq!(known.shout("BAR"), "BAR - Kitty says meow!");
+}
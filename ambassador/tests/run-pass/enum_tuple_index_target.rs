@@ -0,0 +1,57 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+    fn rename(&mut self, name: &str);
+}
+
+pub struct Cat(String);
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {} meows!", input, self.0)
+    }
+    fn rename(&mut self, name: &str) {
+        self.0 = name.to_owned();
+    }
+}
+
+pub struct Dog(String);
+
+impl Shout for Dog {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {} wuffs!", input, self.0)
+    }
+    fn rename(&mut self, name: &str) {
+        self.0 = name.to_owned();
+    }
+}
+
+// The third field of every variant is the target, whatever the other fields are
+#[derive(Delegate)]
+#[delegate(Shout, target = "2")]
+pub enum Animals {
+    Cat(u32, &'static str, Cat),
+    Dog(bool, u8, Dog, Vec<u8>),
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = 2)]
+pub enum Pets<T> {
+    Cat((), (), Cat),
+    Other((), (), T),
+}
+
+pub fn main() {
+    let mut cat = Animals::Cat(3, "tabby", Cat("Tom".to_string()));
+    cat.rename("Felix");
+    assert_eq!(cat.shout("BAR"), "BAR - Felix meows!");
+    let dog = Animals::Dog(true, 1, Dog("Rex".to_string()), vec![]);
+    assert_eq!(dog.shout("BAR"), "BAR - Rex wuffs!");
+
+    let pet: Pets<Cat> = Pets::Other((), (), Cat("Kitty".to_string()));
+    assert_eq!(pet.shout("BAR"), "BAR - Kitty meows!");
+}
@@ -0,0 +1,73 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+use std::collections::HashMap;
+
+#[delegatable_trait]
+pub trait Describe {
+    fn describe(&self) -> String;
+}
+
+#[delegatable_trait]
+pub trait Count<T> {
+    fn count(&self, item: &T) -> usize;
+}
+
+impl Describe for String {
+    fn describe(&self) -> String {
+        format!("text {:?}", self)
+    }
+}
+
+impl<K, V> Describe for HashMap<K, V> {
+    fn describe(&self) -> String {
+        format!("map of {}", self.len())
+    }
+}
+
+impl<T: PartialEq> Describe for Vec<T> {
+    fn describe(&self) -> String {
+        format!("list of {}", self.len())
+    }
+}
+
+impl Count<char> for String {
+    fn count(&self, item: &char) -> usize {
+        self.chars().filter(|c| c == item).count()
+    }
+}
+
+impl<T: PartialEq> Count<T> for Vec<T> {
+    fn count(&self, item: &T) -> usize {
+        self.iter().filter(|x| *x == item).count()
+    }
+}
+
+// Without associated types the variants only need to implement the trait, their types are unrelated
+#[derive(Delegate)]
+#[delegate(Describe)]
+#[delegate(Count<char>)]
+pub enum Value<T> {
+    Text(String),
+    Chars(Vec<char>),
+    Other(T),
+}
+
+#[derive(Delegate)]
+#[delegate(Describe)]
+pub enum Collection {
+    Text(String),
+    Map(HashMap<u8, u8>),
+}
+
+pub fn main() {
+    let text: Value<Vec<char>> = Value::Text("hello".to_string());
+    assert_eq!(text.describe(), "text \"hello\"");
+    assert_eq!(text.count(&'l'), 2);
+    let chars: Value<String> = Value::Chars(vec!['a', 'b', 'a']);
+    assert_eq!(chars.describe(), "list of 3");
+    assert_eq!(chars.count(&'a'), 2);
+    assert_eq!(Value::Other(String::from("aa")).count(&'a'), 2);
+
+    assert_eq!(Collection::Map(HashMap::new()).describe(), "map of 0");
+}
@@ -0,0 +1,67 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+    fn rename(&mut self, name: &str);
+    fn into_name(self) -> String;
+}
+
+pub struct Cat(String);
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {} meows!", input, self.0)
+    }
+    fn rename(&mut self, name: &str) {
+        self.0 = name.to_owned();
+    }
+    fn into_name(self) -> String {
+        self.0
+    }
+}
+
+pub struct Dog(String);
+
+impl Shout for Dog {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {} wuffs!", input, self.0)
+    }
+    fn rename(&mut self, name: &str) {
+        self.0 = name.to_owned();
+    }
+    fn into_name(self) -> String {
+        self.0
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "1")]
+pub enum TupleAnimals {
+    Cat(u32, Cat),
+    Dog(u32, Dog, bool),
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "animal")]
+pub enum NamedAnimals {
+    Cat { age: u32, animal: Cat },
+    Dog { animal: Dog, good: bool },
+}
+
+pub fn main() {
+    let mut cat = TupleAnimals::Cat(3, Cat("Tom".to_owned()));
+    assert_eq!(cat.shout("BAR"), "BAR - Tom meows!");
+    cat.rename("Felix");
+    assert_eq!(cat.into_name(), "Felix");
+    let dog = TupleAnimals::Dog(5, Dog("Rex".to_owned()), true);
+    assert_eq!(dog.shout("BAR"), "BAR - Rex wuffs!");
+
+    let cat = NamedAnimals::Cat { age: 3, animal: Cat("Tom".to_owned()) };
+    assert_eq!(cat.shout("BAR"), "BAR - Tom meows!");
+    let mut dog = NamedAnimals::Dog { animal: Dog("Rex".to_owned()), good: true };
+    dog.rename("Max");
+    assert_eq!(dog.into_name(), "Max");
+}
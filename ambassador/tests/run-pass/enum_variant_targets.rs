@@ -0,0 +1,53 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+    fn rename(&mut self, name: &str);
+}
+
+pub struct Cat(String);
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {} meows!", input, self.0)
+    }
+    fn rename(&mut self, name: &str) {
+        self.0 = name.to_owned();
+    }
+}
+
+pub struct Dog(String);
+
+impl Shout for Dog {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {} wuffs!", input, self.0)
+    }
+    fn rename(&mut self, name: &str) {
+        self.0 = name.to_owned();
+    }
+}
+
+// Each variant picks its own field, whatever its shape
+#[derive(Delegate)]
+#[delegate(Shout, target(Tuple = "1", Named = "animal", Single = "0"))]
+pub enum Animals {
+    Tuple(u32, Cat),
+    Named { age: u32, animal: Dog },
+    Single(Cat),
+}
+
+pub fn main() {
+    let mut tuple = Animals::Tuple(3, Cat("Tom".to_string()));
+    tuple.rename("Felix");
+    assert_eq!(tuple.shout("BAR"), "BAR - Felix meows!");
+    let named = Animals::Named {
+        age: 5,
+        animal: Dog("Rex".to_string()),
+    };
+    assert_eq!(named.shout("BAR"), "BAR - Rex wuffs!");
+    let single = Animals::Single(Cat("Kitty".to_string()));
+    assert_eq!(single.shout("BAR"), "BAR - Kitty meows!");
+}
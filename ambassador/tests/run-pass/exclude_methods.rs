@@ -0,0 +1,57 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Animal {
+    fn name(&self) -> String;
+    fn sound(&self) -> String;
+    fn greet(&self) -> String {
+        format!("{} says hello", self.name())
+    }
+}
+
+pub struct Cat;
+
+impl Animal for Cat {
+    fn name(&self) -> String {
+        "Cat".to_string()
+    }
+
+    fn sound(&self) -> String {
+        "meow".to_string()
+    }
+
+    fn greet(&self) -> String {
+        format!("{} says {}", self.name(), self.sound())
+    }
+}
+
+pub struct Dog;
+
+impl Animal for Dog {
+    fn name(&self) -> String {
+        "Dog".to_string()
+    }
+
+    fn sound(&self) -> String {
+        "wuff".to_string()
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Animal, exclude(greet))]
+pub struct WrappedCat(Cat);
+
+#[derive(Delegate)]
+#[delegate(Animal, exclude(greet))]
+pub enum Animals {
+    Cat(Cat),
+    Dog(Dog),
+}
+
+pub fn main() {
+    assert_eq!(WrappedCat(Cat).greet(), "Cat says hello");
+    assert_eq!(Animals::Cat(Cat).greet(), "Cat says hello");
+    assert_eq!(Animals::Dog(Dog).name(), "Dog");
+}
@@ -0,0 +1,64 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+#[delegatable_trait]
+pub trait Name {
+    fn name(&self) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+impl Name for Cat {
+    fn name(&self) -> String {
+        "cat".to_string()
+    }
+}
+
+pub struct Tag(String);
+
+impl Name for Tag {
+    fn name(&self) -> String {
+        self.0.clone()
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+#[delegate(Name)]
+pub struct Pet {
+    tag: Tag,
+    #[delegate]
+    cat: Cat,
+}
+
+// A "target" value still takes precedence over the marked field
+#[derive(Delegate)]
+#[delegate(Shout)]
+#[delegate(Name, target = "0")]
+pub struct TaggedCat(Tag, #[delegate] Cat);
+
+pub fn main() {
+    let pet = Pet {
+        tag: Tag("Tom".to_string()),
+        cat: Cat,
+    };
+    assert_eq!(pet.shout("hi"), "hi - meow!");
+    assert_eq!(pet.name(), "cat");
+    assert_eq!(pet.tag.name(), "Tom");
+
+    let tagged = TaggedCat(Tag("Tom".to_string()), Cat);
+    assert_eq!(tagged.shout("hi"), "hi - meow!");
+    assert_eq!(tagged.name(), "Tom");
+}
@@ -0,0 +1,74 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait_remote, Delegate};
+use std::fmt::{self, Debug, Display};
+
+#[delegatable_trait_remote]
+trait Display {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+#[delegatable_trait_remote]
+trait Debug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+// Each trait is delegated to a different field, the formatter is passed on as is
+#[derive(Delegate)]
+#[delegate(Display, target = "name")]
+#[delegate(Debug, target = "id")]
+pub struct User {
+    pub id: u32,
+    name: String,
+}
+
+#[derive(Delegate)]
+#[delegate(Display, target = "1")]
+#[delegate(Debug, target = "0")]
+pub struct Tagged(pub &'static str, pub f64);
+
+// A field whose type has an inherent `fmt` method is still formatted through the trait
+pub struct Celsius(f64);
+
+impl Celsius {
+    pub fn fmt(&self) -> String {
+        "inherent".to_string()
+    }
+}
+
+impl Display for Celsius {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}°C", self.0)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Display, target = "temperature")]
+pub struct Reading {
+    place: String,
+    temperature: Celsius,
+}
+
+pub fn main() {
+    let user = User {
+        id: 7,
+        name: "Ferris".to_string(),
+    };
+    assert_eq!(user.to_string(), "Ferris");
+    assert_eq!(format!("{:?}", user), "7");
+    // Width, alignment and the alternate flag reach the field's implementation
+    assert_eq!(format!("[{:>8}]", user), "[  Ferris]");
+    assert_eq!(format!("[{:<4?}]", user), "[7   ]");
+    assert_eq!(format!("{:#x?}", user), "0x7");
+
+    let tagged = Tagged("x", 1.23456);
+    assert_eq!(format!("{:.2}", tagged), "1.23");
+    assert_eq!(format!("{:?}", tagged), "\"x\"");
+
+    let reading = Reading {
+        place: "Oslo".to_string(),
+        temperature: Celsius(-3.5),
+    };
+    assert_eq!(format!("{}: {}", reading.place, reading), "Oslo: -3.5°C");
+    assert_eq!(reading.temperature.fmt(), "inherent");
+}
@@ -0,0 +1,52 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, delegate_to_methods, Delegate};
+
+#[delegatable_trait]
+pub trait Builder {
+    fn with_flag(self, flag: bool) -> Self;
+    fn flag(&self) -> bool;
+}
+
+#[derive(Default)]
+pub struct Options {
+    flag: bool,
+}
+
+impl Builder for Options {
+    fn with_flag(self, flag: bool) -> Self {
+        Options { flag }
+    }
+    fn flag(&self) -> bool {
+        self.flag
+    }
+}
+
+#[derive(Delegate, Default)]
+#[delegate(Builder, from_inner = "Self")]
+pub struct Wrapper(Options);
+
+#[derive(Delegate, Default)]
+#[delegate(Builder, target = "options", from_inner = "|options| Named { options }")]
+pub struct Named {
+    options: Options,
+}
+
+pub struct Boxed(Box<Options>);
+
+#[delegate_to_methods]
+#[delegate(Builder, target_owned = "into_inner", target_ref = "inner", from_inner = "|options| Boxed(Box::new(options))")]
+impl Boxed {
+    fn into_inner(self) -> Options {
+        *self.0
+    }
+    fn inner(&self) -> &Options {
+        &self.0
+    }
+}
+
+pub fn main() {
+    assert!(Wrapper::default().with_flag(true).flag());
+    assert!(Named::default().with_flag(true).flag());
+    assert!(Boxed(Box::new(Options::default())).with_flag(true).flag());
+}
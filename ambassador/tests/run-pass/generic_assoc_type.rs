@@ -0,0 +1,55 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Lend {
+    type Item<'a>
+    where
+        Self: 'a;
+    type Wrapped<T: Clone>: Clone;
+
+    fn lend<'a>(&'a self) -> Self::Item<'a>;
+    fn wrap<T: Clone>(&self, value: T) -> Self::Wrapped<T>;
+}
+
+pub struct Names(Vec<String>);
+
+impl Lend for Names {
+    type Item<'a> = &'a [String]
+    where
+        Self: 'a;
+    type Wrapped<T: Clone> = Option<T>;
+
+    fn lend<'a>(&'a self) -> Self::Item<'a> {
+        &self.0
+    }
+    fn wrap<T: Clone>(&self, value: T) -> Self::Wrapped<T> {
+        Some(value)
+    }
+}
+
+// Generic associated types are delegated to struct targets with their parameters and where clauses
+#[derive(Delegate)]
+#[delegate(Lend)]
+pub struct Wrapper(Names);
+
+#[derive(Delegate)]
+#[delegate(Lend, target = "names")]
+pub struct Named<N> {
+    names: N,
+    id: u32,
+}
+
+pub fn main() {
+    let wrapper = Wrapper(Names(vec!["Tom".to_string()]));
+    assert_eq!(wrapper.lend(), ["Tom".to_string()]);
+    assert_eq!(wrapper.wrap(1), Some(1));
+    let named = Named {
+        names: wrapper,
+        id: 0,
+    };
+    assert_eq!(named.lend().len(), 1);
+    assert_eq!(named.wrap("Rex"), Some("Rex"));
+    assert_eq!(named.id, 0);
+}
@@ -0,0 +1,65 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+use std::fmt::Display;
+
+#[delegatable_trait]
+pub trait Convert {
+    fn convert<T: Into<u64>>(&self, x: T) -> u64;
+    fn describe<T>(&self, x: T) -> String
+    where
+        T: Display;
+    fn size_of<T>(&self) -> usize;
+    fn repeat<const N: usize>(&self) -> [u8; N];
+    fn show(&self, x: impl Display) -> String;
+}
+
+pub struct Doubler;
+
+impl Convert for Doubler {
+    fn convert<T: Into<u64>>(&self, x: T) -> u64 {
+        x.into() * 2
+    }
+
+    fn describe<T>(&self, x: T) -> String
+    where
+        T: Display,
+    {
+        format!("<{}>", x)
+    }
+
+    fn size_of<T>(&self) -> usize {
+        std::mem::size_of::<T>()
+    }
+
+    fn repeat<const N: usize>(&self) -> [u8; N] {
+        [2; N]
+    }
+
+    fn show(&self, x: impl Display) -> String {
+        x.to_string()
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Convert)]
+pub struct Wrapper(Doubler);
+
+#[derive(Delegate)]
+#[delegate(Convert)]
+pub enum Either {
+    Left(Doubler),
+    Right(Wrapper),
+}
+
+pub fn main() {
+    let wrapper = Wrapper(Doubler);
+    assert_eq!(wrapper.convert(21u8), 42);
+    assert_eq!(wrapper.describe("cat"), "<cat>");
+    assert_eq!(wrapper.size_of::<u32>(), 4);
+    assert_eq!(wrapper.repeat::<3>(), [2, 2, 2]);
+    assert_eq!(wrapper.show(7), "7");
+    let either = Either::Right(wrapper);
+    assert_eq!(either.convert(5u32), 10);
+    assert_eq!(either.size_of::<u16>(), 2);
+}
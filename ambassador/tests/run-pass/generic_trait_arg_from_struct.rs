@@ -0,0 +1,56 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Producer<T> {
+    fn produce(&mut self) -> Option<T>;
+}
+
+pub struct Inner<T> {
+    items: Vec<T>,
+}
+
+impl<T> Producer<T> for Inner<T> {
+    fn produce(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+}
+
+// The trait argument is the struct's own type parameter, so `Inner<T>: Producer<T>` is required
+#[derive(Delegate)]
+#[delegate(Producer<T>)]
+pub struct Wrapper<T>(Inner<T>);
+
+#[derive(Delegate)]
+#[delegate(Producer<T>, target = "inner")]
+pub struct Named<T, U> {
+    inner: Inner<T>,
+    label: U,
+}
+
+#[derive(Delegate)]
+#[delegate(Producer<T>)]
+pub enum Either<T> {
+    Plain(Inner<T>),
+    Wrapped(Wrapper<T>),
+}
+
+fn drain<T, P: Producer<T>>(mut producer: P) -> Vec<T> {
+    let mut res = Vec::new();
+    while let Some(item) = producer.produce() {
+        res.push(item);
+    }
+    res
+}
+
+pub fn main() {
+    assert_eq!(drain(Wrapper(Inner { items: vec![1, 2] })), vec![2, 1]);
+    let named = Named {
+        inner: Inner { items: vec!["a"] },
+        label: (),
+    };
+    assert_eq!(drain(named), vec!["a"]);
+    let either = Either::Wrapped(Wrapper(Inner { items: vec![1u8] }));
+    assert_eq!(drain(either), vec![1]);
+}
@@ -16,8 +16,9 @@ impl<'a, E> Ref<'a> for &'a E {
     }
 }
 
+// The trait is implemented by the reference itself, so it isn't delegated to the referenced value
 #[derive(Delegate)]
-#[delegate(Ref<'x>, generics = "'x")]
+#[delegate(Ref<'x>, generics = "'x", automatic_where_clause = "false", where = "&'a T: Ref<'x>")]
 struct WrapRef<'a, T>(&'a T);
 
 fn main() {
@@ -0,0 +1,44 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Converter<T> {
+    type Error;
+    fn convert(&self, input: T) -> Result<String, Self::Error>;
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Negative;
+
+pub struct Printer;
+
+impl Converter<i32> for Printer {
+    type Error = Negative;
+    fn convert(&self, input: i32) -> Result<String, Negative> {
+        if input < 0 {
+            Err(Negative)
+        } else {
+            Ok(input.to_string())
+        }
+    }
+}
+
+impl Converter<bool> for Printer {
+    type Error = ();
+    fn convert(&self, input: bool) -> Result<String, ()> {
+        Ok(input.to_string())
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Converter<i32, Error = Negative>)]
+#[delegate(Converter<bool>)]
+pub struct WrappedPrinter(Printer);
+
+pub fn main() {
+    let printer = WrappedPrinter(Printer);
+    assert_eq!(printer.convert(42), Ok("42".to_string()));
+    assert_eq!(printer.convert(-1), Err(Negative));
+    assert_eq!(printer.convert(true), Ok("true".to_string()));
+}
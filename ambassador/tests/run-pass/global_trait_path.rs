@@ -0,0 +1,37 @@
+// edition:2018
+extern crate ambassador;
+
+use ambassador::{delegatable_trait_remote, Delegate};
+
+// A local trait shadows the name `Display`, the delegated trait is given by its absolute path
+pub trait Display {
+    fn local(&self);
+}
+
+pub struct Cat;
+
+impl ::std::fmt::Display for Cat {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_str("Cat")
+    }
+}
+
+#[delegatable_trait_remote]
+trait Display {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result;
+}
+
+#[derive(Delegate)]
+#[delegate(::std::fmt::Display)]
+pub struct WrappedCat(Cat);
+
+#[derive(Delegate)]
+#[delegate(::std::fmt::Display)]
+pub enum Animals {
+    Cat(Cat),
+}
+
+pub fn main() {
+    assert_eq!(WrappedCat(Cat).to_string(), "Cat");
+    assert_eq!(Animals::Cat(Cat).to_string(), "Cat");
+}
@@ -0,0 +1,57 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+use std::fmt::Display;
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Cat {
+    // Shadows the trait's method with method call syntax
+    pub fn shout(&self, input: &str) -> String {
+        format!("{} - hiss!", input)
+    }
+}
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+pub struct Dog<T>(T);
+
+impl<T: Display> Shout for Dog<T> {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {}!", input, self.0)
+    }
+}
+
+// Without the automatic where clause the trait's method is still called with the `hygienic` flag
+#[derive(Delegate)]
+#[delegate(Shout, no_auto_bound, hygienic)]
+pub struct WrappedCat(Cat);
+
+#[derive(Delegate)]
+#[delegate(Shout, automatic_where_clause = "false", where = "Dog<T>: Shout", hygienic)]
+pub struct WrappedDog<T>(Dog<T>);
+
+#[derive(Delegate)]
+#[delegate(Shout, no_auto_bound, hygienic)]
+pub enum Animal {
+    Cat(Cat),
+    Dog(Dog<&'static str>),
+    Boxed(Box<dyn Shout>),
+}
+
+pub fn main() {
+    assert_eq!(WrappedCat(Cat).shout("a"), "a - meow!");
+    assert_eq!(WrappedDog(Dog("wuff")).shout("a"), "a - wuff!");
+    assert_eq!(Animal::Cat(Cat).shout("a"), "a - meow!");
+    assert_eq!(Animal::Dog(Dog("wuff")).shout("a"), "a - wuff!");
+    assert_eq!(Animal::Boxed(Box::new(Cat)).shout("a"), "a - meow!");
+}
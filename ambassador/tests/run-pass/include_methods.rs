@@ -0,0 +1,58 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, delegate_to_methods, Delegate};
+
+#[delegatable_trait]
+pub trait Animal {
+    fn name(&self) -> String;
+    fn sound(&self) -> String {
+        "...".to_string()
+    }
+    fn greet(&self) -> String {
+        format!("{} says hello", self.name())
+    }
+}
+
+pub struct Cat;
+
+impl Animal for Cat {
+    fn name(&self) -> String {
+        "Cat".to_string()
+    }
+
+    fn sound(&self) -> String {
+        "meow".to_string()
+    }
+
+    fn greet(&self) -> String {
+        format!("{} says {}", self.name(), self.sound())
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Animal, include(name))]
+pub struct WrappedCat(Cat);
+
+#[derive(Delegate)]
+#[delegate(Animal, include(name, sound))]
+pub enum Animals {
+    Cat(Cat),
+}
+
+pub struct BoxedCat(Box<Cat>);
+
+#[delegate_to_methods]
+#[delegate(Animal, target_ref = "inner", include(name))]
+impl BoxedCat {
+    fn inner(&self) -> &Cat {
+        &self.0
+    }
+}
+
+pub fn main() {
+    assert_eq!(WrappedCat(Cat).sound(), "...");
+    assert_eq!(WrappedCat(Cat).greet(), "Cat says hello");
+    assert_eq!(Animals::Cat(Cat).sound(), "meow");
+    assert_eq!(Animals::Cat(Cat).greet(), "Cat says hello");
+    assert_eq!(BoxedCat(Box::new(Cat)).greet(), "Cat says hello");
+}
@@ -0,0 +1,49 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait_remote, Delegate};
+use std::ops::{Index, IndexMut};
+
+#[delegatable_trait_remote]
+pub trait Index<Idx: ?Sized> {
+    type Output: ?Sized;
+    fn index(&self, index: Idx) -> &Self::Output;
+}
+
+#[delegatable_trait_remote]
+pub trait IndexMut<Idx: ?Sized>: Index<Idx> {
+    fn index_mut(&mut self, index: Idx) -> &mut Self::Output;
+}
+
+#[derive(Delegate)]
+#[delegate(Index<usize>)]
+#[delegate(IndexMut<usize>)]
+pub struct Stack(Vec<u32>);
+
+#[derive(Delegate)]
+#[delegate(Index<I>, generics = "I")]
+#[delegate(IndexMut<I>, generics = "I")]
+pub struct Wrapper<T>(Vec<T>);
+
+#[derive(Delegate)]
+#[delegate(Index<usize>)]
+#[delegate(IndexMut<usize>)]
+pub enum Storage {
+    Heap(Vec<u32>),
+    Fixed([u32; 3]),
+}
+
+pub fn main() {
+    let mut stack = Stack(vec![1, 2, 3]);
+    stack[1] = 5;
+    assert_eq!(stack[1], 5);
+
+    let mut wrapper = Wrapper(vec!["a", "b", "c"]);
+    wrapper[0] = "z";
+    assert_eq!(&wrapper[..2], &["z", "b"]);
+
+    let mut storage = Storage::Fixed([1, 2, 3]);
+    storage[2] += 1;
+    assert_eq!(storage[2], 4);
+    let storage = Storage::Heap(vec![7]);
+    assert_eq!(storage[0], 7);
+}
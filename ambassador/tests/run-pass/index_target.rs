@@ -0,0 +1,73 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Animal {
+    type Food;
+    const LEGS: u8;
+    fn name(&self) -> String;
+    fn rename(&mut self, name: &str);
+}
+
+pub struct Cat {
+    name: String,
+}
+
+impl Cat {
+    fn new(name: &str) -> Cat {
+        Cat {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl Animal for Cat {
+    type Food = u32;
+    const LEGS: u8 = 4;
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+    fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Animal, target = "0[0]")]
+pub struct Litter(Vec<Cat>);
+
+#[derive(Delegate)]
+#[delegate(Animal, target = "cells[1]")]
+pub struct Pair {
+    cells: [Cat; 2],
+}
+
+#[derive(Delegate)]
+#[delegate(Animal, target = "0[1]")]
+pub struct Second<T>(Vec<T>);
+
+fn food<T: Animal<Food = u32>>(_: &T) -> u8 {
+    T::LEGS
+}
+
+pub fn main() {
+    let mut litter = Litter(vec![Cat::new("Tom"), Cat::new("Felix")]);
+    assert_eq!(litter.name(), "Tom");
+    litter.rename("Garfield");
+    assert_eq!(litter.0[0].name(), "Garfield");
+    assert_eq!(food(&litter), 4);
+
+    let pair = Pair {
+        cells: [Cat::new("Tom"), Cat::new("Felix")],
+    };
+    assert_eq!(pair.name(), "Felix");
+
+    let mut second = Second(vec![Cat::new("Tom"), Cat::new("Felix")]);
+    second.rename("Garfield");
+    assert_eq!(second.name(), "Garfield");
+
+    // Like any indexing, delegating to an element that isn't there panics
+    let empty = Litter(Vec::new());
+    assert!(std::panic::catch_unwind(|| empty.name()).is_err());
+}
@@ -0,0 +1,78 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+    fn rename(&mut self, name: &str);
+}
+
+pub struct Cat(String);
+
+// Inherent methods take precedence over the trait's in method call syntax,
+// `self.0.shout(input)` would not even compile here and `self.0.rename(name)` would call the wrong method
+impl Cat {
+    pub fn shout(&self) -> String {
+        "inherent".to_string()
+    }
+    pub fn rename(&mut self, _name: &str) {
+        self.0 = "inherent".to_string();
+    }
+}
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {} meows!", input, self.0)
+    }
+    fn rename(&mut self, name: &str) {
+        self.0 = name.to_owned();
+    }
+}
+
+// Struct targets are always called through the trait
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub struct WrappedCat(Cat);
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "cat")]
+pub struct Owner {
+    name: String,
+    cat: Cat,
+}
+
+pub struct Dog;
+
+impl Shout for Dog {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - wuff!", input)
+    }
+    fn rename(&mut self, _name: &str) {}
+}
+
+// The variants' fields are called through the trait as well
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub enum Animal {
+    Cat(Cat),
+    Dog(Dog),
+}
+
+pub fn main() {
+    let mut cat = WrappedCat(Cat("Tom".to_string()));
+    cat.rename("Felix");
+    assert_eq!(cat.shout("BAR"), "BAR - Felix meows!");
+
+    let mut owner = Owner {
+        name: "Jon".to_string(),
+        cat: Cat("Tom".to_string()),
+    };
+    owner.rename("Garfield");
+    assert_eq!(owner.shout(&owner.name), "Jon - Garfield meows!");
+
+    let mut animal = Animal::Cat(Cat("Tom".to_string()));
+    animal.rename("Felix");
+    assert_eq!(Shout::shout(&animal, "BAR"), "BAR - Felix meows!");
+    assert_eq!(Animal::Dog(Dog).shout("BAR"), "BAR - wuff!");
+}
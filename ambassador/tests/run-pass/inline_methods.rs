@@ -0,0 +1,62 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, delegate_to_methods, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+    fn rename(&mut self, name: &str);
+}
+
+pub struct Cat {
+    name: String,
+}
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {} says meow!", input, self.name)
+    }
+
+    fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, inline)]
+pub struct WrappedCat(Cat);
+
+#[derive(Delegate)]
+#[delegate(Shout, inline)]
+pub enum Animals {
+    Cat(Cat),
+    Wrapped(WrappedCat),
+}
+
+pub struct BoxedCat(Box<Cat>);
+
+#[delegate_to_methods]
+#[delegate(Shout, target_ref = "inner", target_mut = "inner_mut", inline)]
+impl BoxedCat {
+    fn inner(&self) -> &Cat {
+        &self.0
+    }
+
+    fn inner_mut(&mut self) -> &mut Cat {
+        &mut self.0
+    }
+}
+
+pub fn main() {
+    let mut animal = Animals::Wrapped(WrappedCat(Cat {
+        name: "Tom".to_string(),
+    }));
+    animal.rename("Felix");
+    assert_eq!(animal.shout("BAR"), "BAR - Felix says meow!");
+
+    let mut boxed = BoxedCat(Box::new(Cat {
+        name: "Tom".to_string(),
+    }));
+    boxed.rename("Felix");
+    assert_eq!(boxed.shout("BAR"), "BAR - Felix says meow!");
+}
@@ -0,0 +1,71 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[delegatable_trait]
+pub trait Counter {
+    type Count;
+    fn count(&self) -> Self::Count;
+    fn increment(&mut self, by: u32);
+    fn into_count(self) -> u32;
+}
+
+pub struct Clicks(u32);
+
+impl Counter for Clicks {
+    type Count = u32;
+    fn count(&self) -> u32 {
+        self.0
+    }
+    fn increment(&mut self, by: u32) {
+        self.0 += by;
+    }
+    fn into_count(self) -> u32 {
+        self.0
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Counter, interior_mut)]
+pub struct Shared(RefCell<Clicks>);
+
+// `&self` methods can still mutate through the shared handle
+#[delegatable_trait]
+pub trait Record {
+    fn record(&self, by: u32);
+}
+
+impl Record for RefCell<Clicks> {
+    fn record(&self, by: u32) {
+        self.borrow_mut().increment(by);
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Counter, target = "clicks", interior_mut)]
+#[delegate(Record, target = "clicks")]
+pub struct Button<T> {
+    label: T,
+    clicks: RefCell<Clicks>,
+}
+
+fn total<C: Counter<Count = u32>>(counter: &C) -> u32 {
+    counter.count()
+}
+
+pub fn main() {
+    let mut shared = Shared(RefCell::new(Clicks(1)));
+    shared.increment(2);
+    assert_eq!(total(&shared), 3);
+    assert_eq!(shared.into_count(), 3);
+
+    let button = Rc::new(Button {
+        label: "ok",
+        clicks: RefCell::new(Clicks(0)),
+    });
+    button.record(5);
+    assert_eq!(button.count(), 5);
+    assert_eq!(button.label, "ok");
+}
@@ -0,0 +1,53 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait_remote, Delegate};
+use std::iter::{DoubleEndedIterator, ExactSizeIterator, Iterator};
+
+// Only the copied methods are delegated, the others keep std's default implementations on top of them
+#[delegatable_trait_remote]
+pub trait Iterator {
+    type Item;
+    fn next(&mut self) -> Option<Self::Item>;
+    fn size_hint(&self) -> (usize, Option<usize>);
+}
+
+#[delegatable_trait_remote]
+pub trait DoubleEndedIterator: Iterator {
+    fn next_back(&mut self) -> Option<Self::Item>;
+}
+
+#[delegatable_trait_remote]
+pub trait ExactSizeIterator: Iterator {
+    fn len(&self) -> usize;
+}
+
+#[derive(Delegate)]
+#[delegate(Iterator)]
+#[delegate(DoubleEndedIterator)]
+#[delegate(ExactSizeIterator)]
+pub struct Queue<T>(std::vec::IntoIter<T>);
+
+impl<T> Queue<T> {
+    pub fn new(items: Vec<T>) -> Self {
+        Queue(items.into_iter())
+    }
+}
+
+pub fn main() {
+    let mut queue = Queue::new(vec!["a", "b", "c", "d"]);
+    assert_eq!(queue.len(), 4);
+    assert_eq!(queue.size_hint(), (4, Some(4)));
+    assert_eq!(queue.next(), Some("a"));
+    assert_eq!(queue.next_back(), Some("d"));
+    assert_eq!(queue.len(), 2);
+    assert_eq!(queue.rev().collect::<Vec<_>>(), vec!["c", "b"]);
+
+    // Adapters and `for` loops work like for the wrapped iterator
+    let total: u32 = Queue::new(vec![1, 2, 3]).map(|n| n * 10).sum();
+    assert_eq!(total, 60);
+    let mut seen = Vec::new();
+    for name in Queue::new(vec!["x".to_string(), "y".to_string()]) {
+        seen.push(name);
+    }
+    assert_eq!(seen, ["x", "y"]);
+}
@@ -0,0 +1,82 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+use std::panic;
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+
+#[delegatable_trait]
+pub trait Counter {
+    fn count(&self) -> u32;
+    fn increment(&mut self, by: u32);
+    fn into_count(self) -> u32;
+}
+
+#[derive(Default)]
+pub struct Clicks(u32);
+
+impl Counter for Clicks {
+    fn count(&self) -> u32 {
+        self.0
+    }
+    fn increment(&mut self, by: u32) {
+        self.0 += by;
+    }
+    fn into_count(self) -> u32 {
+        self.0
+    }
+}
+
+#[delegatable_trait]
+pub trait Record {
+    fn record(&self, by: u32);
+}
+
+impl Record for Mutex<Clicks> {
+    fn record(&self, by: u32) {
+        self.lock().unwrap().increment(by);
+    }
+}
+
+#[derive(Default, Delegate)]
+#[delegate(Counter, lock)]
+#[delegate(Record)]
+pub struct Locked(Mutex<Clicks>);
+
+#[derive(Default, Delegate)]
+#[delegate(Counter, target = "clicks", rwlock, poisoned = "clicks were poisoned")]
+pub struct Shared {
+    clicks: RwLock<Clicks>,
+}
+
+pub fn main() {
+    let locked = Arc::new(Locked::default());
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let locked = locked.clone();
+            thread::spawn(move || locked.record(1))
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(locked.count(), 4);
+    let mut locked = Arc::try_unwrap(locked).ok().unwrap();
+    locked.increment(1);
+    assert_eq!(locked.into_count(), 5);
+
+    let mut shared = Shared::default();
+    shared.increment(2);
+    assert_eq!(shared.count(), 2);
+
+    let shared = Arc::new(shared);
+    let poisoner = shared.clone();
+    let _ = thread::spawn(move || {
+        let _guard = poisoner.clicks.write().unwrap();
+        panic!("poison the lock");
+    })
+    .join();
+    let err = panic::catch_unwind(|| shared.count()).unwrap_err();
+    let message = err.downcast_ref::<String>().unwrap();
+    assert!(message.starts_with("clicks were poisoned"));
+}
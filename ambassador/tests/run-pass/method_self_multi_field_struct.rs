@@ -0,0 +1,50 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait IntoName {
+    fn into_name(self) -> String;
+}
+
+pub struct Cat {
+    name: String,
+}
+
+impl IntoName for Cat {
+    fn into_name(self) -> String {
+        self.name
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(IntoName, target = "cat")]
+pub struct Pet {
+    owner: String,
+    cat: Cat,
+}
+
+#[derive(Delegate)]
+#[delegate(IntoName, target = "1")]
+pub struct Pair(Cat, Cat);
+
+pub fn main() {
+    let pet = Pet {
+        owner: "Jon".to_string(),
+        cat: Cat {
+            name: "Garfield".to_string(),
+        },
+    };
+    assert_eq!(pet.owner, "Jon");
+    assert_eq!(pet.into_name(), "Garfield");
+
+    let pair = Pair(
+        Cat {
+            name: "Tom".to_string(),
+        },
+        Cat {
+            name: "Felix".to_string(),
+        },
+    );
+    assert_eq!(pair.into_name(), "Felix");
+}
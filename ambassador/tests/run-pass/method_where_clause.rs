@@ -0,0 +1,63 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+use std::fmt::Debug;
+
+#[delegatable_trait]
+pub trait Collect {
+    type Item;
+    fn duplicate<T>(&self, t: T) -> (T, T)
+    where
+        T: Clone;
+    fn push_all<I>(&mut self, items: I)
+    where
+        I: IntoIterator<Item = Self::Item>,
+        Self::Item: Debug;
+    fn longest<'a, 'b, T>(&'a self, other: &'b T) -> &'a str
+    where
+        'b: 'a,
+        T: AsRef<str> + ?Sized;
+    fn into_items(self) -> Vec<Self::Item>
+    where
+        Self: Sized;
+}
+
+pub struct Bag(Vec<u8>);
+
+impl Collect for Bag {
+    type Item = u8;
+    fn duplicate<T>(&self, t: T) -> (T, T)
+    where
+        T: Clone,
+    {
+        (t.clone(), t)
+    }
+    fn push_all<I>(&mut self, items: I)
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        self.0.extend(items)
+    }
+    fn longest<'a, 'b, T>(&'a self, _other: &'b T) -> &'a str
+    where
+        'b: 'a,
+        T: AsRef<str> + ?Sized,
+    {
+        "bag"
+    }
+    fn into_items(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Collect)]
+pub struct Wrapper(Bag);
+
+pub fn main() {
+    let mut wrapper = Wrapper(Bag(vec![1]));
+    assert_eq!(wrapper.duplicate("cat"), ("cat", "cat"));
+    wrapper.push_all(vec![2, 3]);
+    assert_eq!(wrapper.longest("other"), "bag");
+    assert_eq!(wrapper.into_items(), vec![1, 2, 3]);
+}
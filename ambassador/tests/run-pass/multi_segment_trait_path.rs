@@ -0,0 +1,74 @@
+extern crate ambassador;
+
+use ambassador::{delegate_to_methods, Delegate};
+
+// `Shout` is never imported, it is only referred to by its full path
+#[macro_use]
+mod animals {
+    use ambassador::delegatable_trait;
+
+    #[delegatable_trait]
+    pub trait Shout {
+        type Voice;
+        fn shout(&self, input: &str) -> String;
+    }
+
+    pub struct Cat;
+
+    impl Shout for Cat {
+        type Voice = ();
+        fn shout(&self, input: &str) -> String {
+            format!("{} - meow!", input)
+        }
+    }
+
+    pub struct Dog;
+
+    impl Shout for Dog {
+        type Voice = ();
+        fn shout(&self, input: &str) -> String {
+            format!("{} - wuff!", input)
+        }
+    }
+}
+
+use animals::{Cat, Dog};
+
+#[derive(Delegate)]
+#[delegate(animals::Shout)]
+pub struct WrappedCat(Cat);
+
+#[derive(Delegate)]
+#[delegate(crate::animals::Shout, target = "1")]
+pub struct WrappedDog(u32, Dog);
+
+#[derive(Delegate)]
+#[delegate(animals::Shout)]
+pub enum Animals {
+    Cat(Cat),
+    Dog(Dog),
+}
+
+#[derive(Delegate)]
+#[delegate(self::animals::Shout)] // relative to this module, not to the module generated for the impl
+pub enum Pets {
+    Cat(Cat),
+}
+
+pub struct BoxedCat(Box<Cat>);
+
+#[delegate_to_methods]
+#[delegate(animals::Shout, target_ref = "inner")]
+impl BoxedCat {
+    fn inner(&self) -> &Cat {
+        &self.0
+    }
+}
+
+pub fn main() {
+    assert_eq!(animals::Shout::shout(&WrappedCat(Cat), "BAR"), "BAR - meow!");
+    assert_eq!(animals::Shout::shout(&WrappedDog(0, Dog), "BAR"), "BAR - wuff!");
+    assert_eq!(animals::Shout::shout(&Animals::Dog(Dog), "BAR"), "BAR - wuff!");
+    assert_eq!(animals::Shout::shout(&Pets::Cat(Cat), "BAR"), "BAR - meow!");
+    assert_eq!(animals::Shout::shout(&BoxedCat(Box::new(Cat)), "BAR"), "BAR - meow!");
+}
@@ -0,0 +1,84 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, delegate_to_methods, Delegate};
+
+mod animals {
+    use ambassador::delegatable_trait;
+
+    #[delegatable_trait]
+    pub trait Walk {
+        fn legs(&self) -> usize;
+    }
+}
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+#[delegatable_trait]
+pub trait Named<T> {
+    fn name(&self) -> T;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+impl animals::Walk for Cat {
+    fn legs(&self) -> usize {
+        4
+    }
+}
+
+impl Named<String> for Cat {
+    fn name(&self) -> String {
+        "Tom".to_string()
+    }
+}
+
+use animals::Walk;
+
+#[derive(Delegate)]
+#[delegate(Shout, animals::Walk, Named<String>, target = "cat", inline)]
+pub struct Pair {
+    cat: Cat,
+    other: Cat,
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, Walk)]
+pub enum Animals {
+    Cat(Cat),
+    Pair(Pair),
+}
+
+pub struct Boxed(Box<Cat>);
+
+#[delegate_to_methods]
+#[delegate(Shout, Walk, target_ref = "inner")]
+impl Boxed {
+    fn inner(&self) -> &Cat {
+        &self.0
+    }
+}
+
+pub fn main() {
+    let pair = Pair {
+        cat: Cat,
+        other: Cat,
+    };
+    assert_eq!(pair.shout("BAR"), "BAR - meow!");
+    assert_eq!(pair.legs(), 4);
+    assert_eq!(pair.name(), "Tom");
+    let animal = Animals::Pair(pair);
+    assert_eq!(animal.shout("BAR"), "BAR - meow!");
+    assert_eq!(animal.legs(), 4);
+    let boxed = Boxed(Box::new(Cat));
+    assert_eq!(boxed.shout("BAR"), "BAR - meow!");
+    assert_eq!(boxed.legs(), 4);
+}
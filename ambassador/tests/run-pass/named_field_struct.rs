@@ -0,0 +1,58 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+#[delegatable_trait]
+pub trait Name {
+    fn name(&self) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+impl Name for Cat {
+    fn name(&self) -> String {
+        "Tom".to_string()
+    }
+}
+
+pub struct Dog;
+
+impl Shout for Dog {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - wuff!", input)
+    }
+}
+
+impl Name for Dog {
+    fn name(&self) -> String {
+        "Rex".to_string()
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "left")]
+#[delegate(Name, target = "right")]
+pub struct Pair {
+    left: Cat,
+    right: Dog,
+}
+
+pub fn main() {
+    let pair = Pair {
+        left: Cat,
+        right: Dog,
+    };
+    assert_eq!(pair.shout("BAR"), "BAR - meow!");
+    assert_eq!(pair.name(), "Rex");
+}
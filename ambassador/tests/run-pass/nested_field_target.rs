@@ -0,0 +1,60 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+    fn rename(&mut self, name: &str);
+}
+
+pub struct Cat {
+    name: String,
+}
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {} says meow!", input, self.name)
+    }
+    fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+}
+
+pub struct Wrapper {
+    pub inner: Cat,
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "wrapper.inner")]
+pub struct Owner {
+    wrapper: Wrapper,
+    age: u32,
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "1.0")]
+pub struct Pair(u32, (Cat, u32));
+
+pub fn main() {
+    let mut owner = Owner {
+        wrapper: Wrapper {
+            inner: Cat {
+                name: "Tom".to_string(),
+            },
+        },
+        age: 3,
+    };
+    owner.rename("Felix");
+    assert_eq!(owner.shout("BAR"), "BAR - Felix says meow!");
+    let pair = Pair(
+        1,
+        (
+            Cat {
+                name: "Tom".to_string(),
+            },
+            2,
+        ),
+    );
+    assert_eq!(pair.shout("BAR"), "BAR - Tom says meow!");
+}
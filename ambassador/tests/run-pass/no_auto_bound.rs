@@ -0,0 +1,41 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, delegate_to_methods, Delegate};
+use std::fmt::Display;
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+// Every `Display` type can shout, so `A: Shout` follows from `A: Display`
+impl<T: Display> Shout for T {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {}!", input, self)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, no_auto_bound, where = "A: Display")] // only `A: Display`, no redundant `A: Shout`
+pub struct Wrapper<A>(A);
+
+pub struct Named<A> {
+    name: A,
+}
+
+#[delegate_to_methods]
+#[delegate(Shout, target_ref = "name", no_auto_bound, where = "A: Display")]
+impl<A> Named<A> {
+    fn name(&self) -> &A {
+        &self.name
+    }
+}
+
+fn shout<T: Shout>(shouter: T) -> String {
+    shouter.shout("BAR")
+}
+
+pub fn main() {
+    assert_eq!(shout(Wrapper("meow")), "BAR - meow!");
+    assert_eq!(shout(Named { name: 42 }), "BAR - 42!");
+}
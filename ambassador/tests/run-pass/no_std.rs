@@ -0,0 +1,80 @@
+#![no_std]
+
+// Only linked for the runtime of the test binary, under another name so generated code can't refer to `std`
+extern crate std as _std;
+
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, delegate_to_methods, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    const LOUDNESS: u8;
+    type Voice;
+    fn shout(&self) -> &'static str;
+    fn quiet(&mut self);
+}
+
+pub struct Cat(u8);
+
+impl Shout for Cat {
+    const LOUDNESS: u8 = 3;
+    type Voice = ();
+    fn shout(&self) -> &'static str {
+        "meow"
+    }
+    fn quiet(&mut self) {
+        self.0 = 0;
+    }
+}
+
+pub struct Dog(u8);
+
+impl Shout for Dog {
+    const LOUDNESS: u8 = 3;
+    type Voice = ();
+    fn shout(&self) -> &'static str {
+        "wuff"
+    }
+    fn quiet(&mut self) {
+        self.0 = 0;
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub struct WrappedCat(Cat);
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "*")]
+pub struct RefCat<'a>(&'a mut Cat);
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub enum Animals {
+    Cat(Cat),
+    Dog(Dog),
+}
+
+pub struct Owner(Cat);
+
+#[delegate_to_methods]
+#[delegate(Shout, target_ref = "cat", target_mut = "cat_mut")]
+impl Owner {
+    fn cat(&self) -> &Cat {
+        &self.0
+    }
+    fn cat_mut(&mut self) -> &mut Cat {
+        &mut self.0
+    }
+}
+
+pub fn main() {
+    let mut cat = WrappedCat(Cat(1));
+    cat.quiet();
+    assert_eq!((cat.0).0, 0);
+    assert_eq!(RefCat(&mut Cat(1)).shout(), "meow");
+    assert_eq!(<Animals as Shout>::LOUDNESS, 3);
+    assert_eq!(Animals::Dog(Dog(1)).shout(), "wuff");
+    assert_eq!(Owner(Cat(1)).shout(), "meow");
+}
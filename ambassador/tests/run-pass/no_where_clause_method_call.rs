@@ -0,0 +1,45 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+use std::rc::Rc;
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+// `Rc<Cat>` doesn't implement `Shout`, the method is reached through auto-deref
+#[derive(Delegate)]
+#[delegate(Shout, automatic_where_clause = "false")]
+pub struct RcAnimal(Rc<Cat>);
+
+// `Parrot` doesn't implement `Shout` either, it only has a compatible inherent method
+pub struct Parrot;
+
+impl Parrot {
+    pub fn shout(&self, input: &str) -> String {
+        format!("{} - squawk", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, automatic_where_clause = "false")]
+pub struct P(Parrot);
+
+#[derive(Delegate)]
+#[delegate(Shout, no_auto_bound)]
+pub struct Q(Parrot);
+
+pub fn main() {
+    assert_eq!(RcAnimal(Rc::new(Cat)).shout("a"), "a - meow!");
+    assert_eq!(P(Parrot).shout("a"), "a - squawk");
+    assert_eq!(Q(Parrot).shout("a"), "a - squawk");
+}
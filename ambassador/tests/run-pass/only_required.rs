@@ -0,0 +1,98 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, delegate_to_methods, Delegate};
+
+#[delegatable_trait]
+pub trait Animal {
+    type Food;
+
+    fn name(&self) -> String;
+    fn rename(&mut self, name: &str);
+    fn greet(&self) -> String {
+        format!("Hello, I'm {}", self.name())
+    }
+    fn into_name(self) -> String
+    where
+        Self: Sized,
+    {
+        self.name()
+    }
+}
+
+pub struct Cat(String);
+
+impl Animal for Cat {
+    type Food = u8;
+
+    fn name(&self) -> String {
+        self.0.clone()
+    }
+    fn rename(&mut self, name: &str) {
+        self.0 = name.to_owned();
+    }
+    fn greet(&self) -> String {
+        "meow".to_string()
+    }
+    fn into_name(self) -> String {
+        "the cat".to_string()
+    }
+}
+
+pub struct Dog;
+
+impl Animal for Dog {
+    type Food = u8;
+
+    fn name(&self) -> String {
+        "Rex".to_string()
+    }
+    fn rename(&mut self, _name: &str) {}
+    fn greet(&self) -> String {
+        "wuff".to_string()
+    }
+}
+
+// The default methods run for the wrapper and call the delegated required ones
+#[derive(Delegate)]
+#[delegate(Animal, only_required)]
+pub struct Named(Cat);
+
+#[derive(Delegate)]
+#[delegate(Animal, only_required)]
+pub enum Pets {
+    Cat(Cat),
+    Dog(Dog),
+}
+
+pub struct Holder(Cat);
+
+#[delegate_to_methods]
+#[delegate(Animal, only_required, target_ref = "get", target_mut = "get_mut")]
+impl Holder {
+    fn get(&self) -> &Cat {
+        &self.0
+    }
+    fn get_mut(&mut self) -> &mut Cat {
+        &mut self.0
+    }
+}
+
+fn food<T: Animal<Food = u8>>(_: &T) {}
+
+pub fn main() {
+    let mut named = Named(Cat("Tom".to_string()));
+    named.rename("Felix");
+    assert_eq!(named.name(), "Felix");
+    assert_eq!(named.greet(), "Hello, I'm Felix");
+    food(&named);
+
+    let pet = Pets::Dog(Dog);
+    assert_eq!(pet.greet(), "Hello, I'm Rex");
+    assert_eq!(pet.into_name(), "Rex");
+    assert_eq!(Pets::Cat(Cat("Tom".to_string())).greet(), "Hello, I'm Tom");
+
+
+    let holder = Holder(Cat("Tom".to_string()));
+    assert_eq!(holder.greet(), "Hello, I'm Tom");
+    assert_eq!(holder.into_name(), "Tom");
+}
@@ -0,0 +1,58 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    type Voice;
+    fn shout(&self, input: &str) -> String;
+    fn rename(&mut self, name: &str);
+    fn into_name(self) -> String;
+}
+
+pub struct Cat {
+    name: String,
+}
+
+impl Shout for Cat {
+    type Voice = ();
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {} says meow!", input, self.name)
+    }
+    fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+    fn into_name(self) -> String {
+        self.name
+    }
+}
+
+// The automatic where clause bounds `T: Shout`, not `Option<T>: Shout`
+#[derive(Delegate)]
+#[delegate(Shout, unwrap)]
+pub struct Lazy<T>(Option<T>);
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "cat", unwrap)]
+pub struct Owner {
+    cat: Option<Cat>,
+    name: String,
+}
+
+pub fn main() {
+    let mut lazy = Lazy(Some(Cat {
+        name: "Tom".to_string(),
+    }));
+    lazy.rename("Felix");
+    assert_eq!(lazy.shout("BAR"), "BAR - Felix says meow!");
+    assert_eq!(lazy.into_name(), "Felix");
+
+    let owner = Owner {
+        cat: None,
+        name: "Jon".to_string(),
+    };
+    let err = std::panic::catch_unwind(|| owner.shout("BAR")).unwrap_err();
+    let message = err.downcast_ref::<String>().unwrap();
+    assert_eq!(message, "`cat` field of `Owner` is `None`");
+    assert_eq!(owner.name, "Jon");
+}
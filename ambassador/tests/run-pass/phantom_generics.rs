@@ -0,0 +1,58 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+use std::marker::PhantomData;
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+#[delegatable_trait]
+pub trait Describe<T> {
+    fn describe(&self, value: T) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+impl<T: std::fmt::Debug> Describe<T> for Cat {
+    fn describe(&self, value: T) -> String {
+        format!("cat sees {:?}", value)
+    }
+}
+
+// Not `Shout`, so a `T: Shout` bound would make the impls unusable
+pub struct Meters;
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "0")]
+#[delegate(Describe<T>, target = "0")]
+pub struct Tagged<T>(Cat, PhantomData<T>);
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "inner")]
+#[delegate(Describe<U>, target = "inner", generics = "U")]
+pub struct Marked<M, I> {
+    inner: I,
+    marker: PhantomData<fn() -> M>,
+}
+
+pub fn main() {
+    let tagged: Tagged<Meters> = Tagged(Cat, PhantomData);
+    assert_eq!(tagged.shout("BAR"), "BAR - meow!");
+    let tagged: Tagged<u8> = Tagged(Cat, PhantomData);
+    assert_eq!(tagged.describe(3), "cat sees 3");
+
+    let marked: Marked<Meters, Cat> = Marked {
+        inner: Cat,
+        marker: PhantomData,
+    };
+    assert_eq!(marked.shout("BAR"), "BAR - meow!");
+    assert_eq!(marked.describe("x"), "cat sees \"x\"");
+}
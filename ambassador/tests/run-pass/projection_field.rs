@@ -0,0 +1,50 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+pub trait Family {
+    type Pet;
+}
+
+pub struct Cats;
+
+impl Family for Cats {
+    type Pet = Cat;
+}
+
+// The struct's own `T: Family` bound is kept next to the automatic `T::Pet: Shout` bound
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub struct Inline<T: Family>(T::Pet);
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub struct WhereClause<T>(<T as Family>::Pet)
+where
+    T: Family;
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub enum Either<T: Family> {
+    Pet(T::Pet),
+    Cat(Cat),
+}
+
+pub fn main() {
+    assert_eq!(Inline::<Cats>(Cat).shout("BAR"), "BAR - meow!");
+    assert_eq!(WhereClause::<Cats>(Cat).shout("BAR"), "BAR - meow!");
+    assert_eq!(Either::Pet::<Cats>(Cat).shout("BAR"), "BAR - meow!");
+}
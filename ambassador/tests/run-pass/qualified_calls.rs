@@ -0,0 +1,83 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+use std::sync::Mutex;
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+    fn rename(&mut self, name: &str);
+    fn into_name(self) -> String
+    where
+        Self: Sized;
+}
+
+pub struct Cat(String);
+
+// Inherent methods with the same names as the trait's are picked by method call syntax
+impl Cat {
+    pub fn shout(&self) -> String {
+        "inherent".to_string()
+    }
+    pub fn rename(&mut self) {}
+    pub fn into_name(self) -> u32 {
+        0
+    }
+}
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {} meows!", input, self.0)
+    }
+    fn rename(&mut self, name: &str) {
+        self.0 = name.to_owned();
+    }
+    fn into_name(self) -> String {
+        self.0
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub struct WrappedCat(Cat);
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "*")]
+pub struct BoxedCat(Box<Cat>);
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "inner.cat")]
+pub struct Nested {
+    inner: WrappedCat2,
+}
+
+pub struct WrappedCat2 {
+    cat: Cat,
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "0", lock)]
+pub struct Locked(Mutex<Cat>);
+
+pub fn main() {
+    let mut cat = WrappedCat(Cat("Tom".to_string()));
+    cat.rename("Felix");
+    assert_eq!(cat.shout("BAR"), "BAR - Felix meows!");
+    assert_eq!(cat.into_name(), "Felix");
+
+    let mut boxed = BoxedCat(Box::new(Cat("Tom".to_string())));
+    boxed.rename("Felix");
+    assert_eq!(boxed.shout("BAR"), "BAR - Felix meows!");
+
+    let nested = Nested {
+        inner: WrappedCat2 {
+            cat: Cat("Tom".to_string()),
+        },
+    };
+    assert_eq!(nested.shout("BAR"), "BAR - Tom meows!");
+
+    let mut locked = Locked(Mutex::new(Cat("Tom".to_string())));
+    locked.rename("Felix");
+    assert_eq!(locked.shout("BAR"), "BAR - Felix meows!");
+    assert_eq!(locked.into_name(), "Felix");
+}
@@ -0,0 +1,114 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+use std::rc::Rc;
+
+#[delegatable_trait]
+pub trait Animal {
+    fn name(&self) -> String {
+        "unknown".to_string()
+    }
+    fn rename(&mut self, _name: &str) {}
+    fn into_name(self) -> String
+    where
+        Self: Sized,
+    {
+        "unknown".to_string()
+    }
+    fn kind() -> &'static str
+    where
+        Self: Sized;
+}
+
+pub struct Cat {
+    name: String,
+}
+
+impl Cat {
+    fn new() -> Cat {
+        Cat {
+            name: "Tom".to_string(),
+        }
+    }
+}
+
+impl Animal for Cat {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+    fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+    fn into_name(self) -> String {
+        self.name
+    }
+    fn kind() -> &'static str {
+        "cat"
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Animal, receivers = "value")]
+pub struct Owned(Cat);
+
+#[derive(Delegate)]
+#[delegate(Animal, receivers = "ref")]
+pub struct Shared(Cat);
+
+#[derive(Delegate)]
+#[delegate(Animal, receivers = "ref_mut")]
+pub struct Mutable(Cat);
+
+#[derive(Delegate)]
+#[delegate(Animal, receivers = "value, ref")]
+pub struct NotMutable(Cat);
+
+#[derive(Delegate)]
+#[delegate(Animal, receivers = "value, ref_mut")]
+pub struct NotShared(Cat);
+
+#[derive(Delegate)]
+#[delegate(Animal, receivers = "ref, ref_mut")]
+pub struct Borrowed(Cat);
+
+#[derive(Delegate)]
+#[delegate(Animal, receivers = "ref_mut, ref, value")]
+pub struct All(Cat);
+
+// Owned and mutable access isn't possible through an `Rc`, so no target is needed for them
+#[derive(Delegate)]
+#[delegate(Animal, target = "*", receivers = "ref")]
+pub struct Counted(Rc<Cat>);
+
+/// Renames the animal and returns the names seen through each receiver
+fn names<T: Animal>(mut animal: T) -> (String, String, &'static str) {
+    animal.rename("Felix");
+    (animal.name(), animal.into_name(), T::kind())
+}
+
+pub fn main() {
+    let unknown = || "unknown".to_string();
+    assert_eq!(names(Owned(Cat::new())), (unknown(), "Tom".to_string(), "cat"));
+    assert_eq!(names(Shared(Cat::new())), ("Tom".to_string(), unknown(), "cat"));
+    assert_eq!(names(Mutable(Cat::new())), (unknown(), unknown(), "cat"));
+    assert_eq!(
+        names(NotMutable(Cat::new())),
+        ("Tom".to_string(), "Tom".to_string(), "cat")
+    );
+    assert_eq!(
+        names(NotShared(Cat::new())),
+        (unknown(), "Felix".to_string(), "cat")
+    );
+    assert_eq!(
+        names(Borrowed(Cat::new())),
+        ("Felix".to_string(), unknown(), "cat")
+    );
+    assert_eq!(
+        names(All(Cat::new())),
+        ("Felix".to_string(), "Felix".to_string(), "cat")
+    );
+    assert_eq!(
+        names(Counted(Rc::new(Cat::new()))),
+        ("Tom".to_string(), unknown(), "cat")
+    );
+}
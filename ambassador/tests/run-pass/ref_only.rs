@@ -0,0 +1,70 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, delegate_to_methods, Delegate};
+use std::sync::Arc;
+
+#[delegatable_trait]
+pub trait Animal {
+    fn name(&self) -> String;
+    fn rename(&mut self, _name: &str) {}
+    fn into_name(self) -> String
+    where
+        Self: Sized,
+    {
+        "unknown".to_string()
+    }
+    fn kind() -> &'static str
+    where
+        Self: Sized;
+}
+
+pub struct Cat {
+    name: String,
+}
+
+impl Animal for Cat {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+    fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+    fn into_name(self) -> String {
+        self.name
+    }
+    fn kind() -> &'static str {
+        "cat"
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Animal, target = "*", ref_only)]
+pub struct SharedCat(Arc<Cat>);
+
+pub struct Handle {
+    cat: Arc<Cat>,
+}
+
+#[delegate_to_methods]
+#[delegate(Animal, target_ref = "cat", ref_only)]
+impl Handle {
+    fn cat(&self) -> &Cat {
+        &self.cat
+    }
+}
+
+pub fn main() {
+    let cat = Arc::new(Cat {
+        name: "Tom".to_string(),
+    });
+    let mut shared = SharedCat(cat.clone());
+    shared.rename("Felix");
+    assert_eq!(shared.name(), "Tom");
+    assert_eq!(shared.into_name(), "unknown");
+    assert_eq!(SharedCat::kind(), "cat");
+
+    let mut handle = Handle { cat };
+    handle.rename("Felix");
+    assert_eq!(handle.name(), "Tom");
+    assert_eq!(handle.into_name(), "unknown");
+}
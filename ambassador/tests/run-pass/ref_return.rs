@@ -0,0 +1,64 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Accessor<T> {
+    fn get(&self) -> &T;
+    fn get_mut(&mut self) -> &mut T;
+    fn get_or<'a>(&'a self, fallback: &'a T) -> &'a T;
+    fn split(&self) -> (&T, &str);
+}
+
+pub struct Slot<T> {
+    value: T,
+    label: String,
+}
+
+impl<T> Accessor<T> for Slot<T> {
+    fn get(&self) -> &T {
+        &self.value
+    }
+    fn get_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+    fn get_or<'a>(&'a self, _fallback: &'a T) -> &'a T {
+        &self.value
+    }
+    fn split(&self) -> (&T, &str) {
+        (&self.value, &self.label)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Accessor<T>)]
+pub struct Wrapper<T>(Slot<T>);
+
+#[derive(Delegate)]
+#[delegate(Accessor<u8>)]
+pub enum Either {
+    Left(Slot<u8>),
+    Right(Wrapper<u8>),
+}
+
+pub fn main() {
+    let mut wrapper = Wrapper(Slot {
+        value: 1,
+        label: "one".to_string(),
+    });
+    *wrapper.get_mut() += 1;
+    assert_eq!(*wrapper.get(), 2);
+    assert_eq!(*wrapper.get_or(&5), 2);
+    assert_eq!(wrapper.split(), (&2, "one"));
+
+    let mut either = Either::Right(wrapper);
+    *either.get_mut() += 1;
+    assert_eq!(*either.get(), 3);
+    let left = Either::Left(Slot {
+        value: 7,
+        label: "seven".to_string(),
+    });
+    let fallback = 0;
+    assert_eq!(*left.get_or(&fallback), 7);
+    assert_eq!(left.split(), (&7, "seven"));
+}
@@ -0,0 +1,69 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Inspect {
+    type Id;
+    fn id(&self) -> Self::Id;
+    fn describe(&self, prefix: &str) -> String;
+}
+
+#[delegatable_trait]
+pub trait Feed {
+    fn feed(&mut self, amount: u32);
+}
+
+pub struct Cat {
+    id: u32,
+    food: u32,
+}
+
+impl Inspect for Cat {
+    type Id = u32;
+    fn id(&self) -> u32 {
+        self.id
+    }
+    fn describe(&self, prefix: &str) -> String {
+        format!("{} cat #{} ate {}", prefix, self.id, self.food)
+    }
+}
+
+impl Feed for Cat {
+    fn feed(&mut self, amount: u32) {
+        self.food += amount;
+    }
+}
+
+// `&'a Cat` doesn't implement `Inspect`, the bound is on `Cat` itself
+#[derive(Delegate)]
+#[delegate(Inspect, target = "*")]
+pub struct Borrowed<'a>(&'a Cat);
+
+#[derive(Delegate)]
+#[delegate(Inspect, target = "*cat")]
+#[delegate(Feed, target = "*cat")]
+pub struct Keeper<'a, T> {
+    name: &'static str,
+    cat: &'a mut T,
+}
+
+fn id_of<T: Inspect<Id = u32>>(value: &T) -> u32 {
+    value.id()
+}
+
+pub fn main() {
+    let mut cat = Cat { id: 7, food: 0 };
+    {
+        let mut keeper = Keeper {
+            name: "Jon",
+            cat: &mut cat,
+        };
+        keeper.feed(3);
+        assert_eq!(keeper.describe(keeper.name), "Jon cat #7 ate 3");
+        assert_eq!(id_of(&keeper), 7);
+    }
+    let borrowed = Borrowed(&cat);
+    assert_eq!(borrowed.describe("a"), "a cat #7 ate 3");
+    assert_eq!(id_of(&borrowed), 7);
+}
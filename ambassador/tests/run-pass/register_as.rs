@@ -0,0 +1,66 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+mod animals {
+    use ambassador::delegatable_trait;
+
+    #[delegatable_trait(as = "AnimalShout")]
+    pub trait Shout {
+        type Voice;
+        const LOUD: bool;
+        fn shout(&self, input: &str) -> String;
+    }
+}
+
+// Would clash with the helper macro of `animals::Shout` if that wasn't registered under another name
+#[delegatable_trait]
+pub trait Shout {
+    fn speak(&self) -> String;
+}
+
+pub struct Cat;
+
+impl animals::Shout for Cat {
+    type Voice = ();
+    const LOUD: bool = false;
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+impl Shout for Cat {
+    fn speak(&self) -> String {
+        "meow!".to_string()
+    }
+}
+
+pub struct Dog;
+
+impl animals::Shout for Dog {
+    type Voice = ();
+    const LOUD: bool = false;
+    fn shout(&self, input: &str) -> String {
+        format!("{} - wuff!", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(animals::Shout, as = "AnimalShout")]
+#[delegate(Shout)]
+pub struct WrappedCat(Cat);
+
+#[derive(Delegate)]
+#[delegate(animals::Shout, as = "AnimalShout")]
+pub enum Animals {
+    Cat(Cat),
+    Dog(Dog),
+}
+
+pub fn main() {
+    use animals::Shout as _;
+    assert_eq!(WrappedCat(Cat).shout("BAR"), "BAR - meow!");
+    assert_eq!(WrappedCat(Cat).speak(), "meow!");
+    assert_eq!(Animals::Dog(Dog).shout("BAR"), "BAR - wuff!");
+    assert!(!<Animals as animals::Shout>::LOUD);
+}
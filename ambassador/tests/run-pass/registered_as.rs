@@ -0,0 +1,64 @@
+extern crate ambassador;
+
+use ambassador::{delegate_to_methods, Delegate};
+
+mod animals {
+    use ambassador::delegatable_trait;
+
+    #[delegatable_trait]
+    pub trait Shout {
+        type Voice;
+        fn shout(&self, input: &str) -> String;
+    }
+}
+
+pub mod prelude {
+    pub use super::animals::Shout as Yell;
+}
+
+use prelude::Yell;
+
+pub struct Cat;
+
+impl Yell for Cat {
+    type Voice = ();
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+pub struct Dog;
+
+impl Yell for Dog {
+    type Voice = ();
+    fn shout(&self, input: &str) -> String {
+        format!("{} - wuff!", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Yell, as = "Shout")]
+pub struct WrappedCat(Cat);
+
+#[derive(Delegate)]
+#[delegate(prelude::Yell, as = "Shout")]
+pub enum Animals {
+    Cat(Cat),
+    Dog(Dog),
+}
+
+pub struct BoxedCat(Box<Cat>);
+
+#[delegate_to_methods]
+#[delegate(Yell, target_ref = "inner", as = "Shout")]
+impl BoxedCat {
+    fn inner(&self) -> &Cat {
+        &self.0
+    }
+}
+
+pub fn main() {
+    assert_eq!(WrappedCat(Cat).shout("BAR"), "BAR - meow!");
+    assert_eq!(Animals::Dog(Dog).shout("BAR"), "BAR - wuff!");
+    assert_eq!(BoxedCat(Box::new(Cat)).shout("BAR"), "BAR - meow!");
+}
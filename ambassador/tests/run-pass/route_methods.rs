@@ -0,0 +1,93 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Animal {
+    fn name(&self) -> String;
+    fn sound(&self) -> String;
+    fn rename(&mut self, name: &str);
+    fn greet(&self) -> String {
+        format!("{} says {}", self.name(), self.sound())
+    }
+}
+
+pub struct Name(String);
+
+impl Animal for Name {
+    fn name(&self) -> String {
+        self.0.clone()
+    }
+    fn sound(&self) -> String {
+        unimplemented!()
+    }
+    fn rename(&mut self, name: &str) {
+        self.0 = name.to_owned();
+    }
+}
+
+pub struct Voice(&'static str);
+
+impl Animal for Voice {
+    fn name(&self) -> String {
+        unimplemented!()
+    }
+    fn sound(&self) -> String {
+        self.0.to_owned()
+    }
+    fn rename(&mut self, _name: &str) {
+        unimplemented!()
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Animal, route(name = "name", rename = "name", sound = "voice"))] // `greet` uses the default
+pub struct Cat {
+    name: Name,
+    voice: Voice,
+}
+
+#[delegatable_trait]
+pub trait Legs {
+    const LEGS: usize;
+    fn legs(&self) -> usize;
+    fn describe(&self) -> String;
+}
+
+impl Legs for Name {
+    const LEGS: usize = 0;
+    fn legs(&self) -> usize {
+        Self::LEGS
+    }
+    fn describe(&self) -> String {
+        format!("{} with {} legs", self.0, self.legs())
+    }
+}
+
+impl Legs for Voice {
+    const LEGS: usize = 4;
+    fn legs(&self) -> usize {
+        Self::LEGS
+    }
+    fn describe(&self) -> String {
+        unimplemented!()
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Legs, target = "1", route(describe = "0"))] // `LEGS` and `legs` go to the main target
+pub struct Dog(Name, Voice);
+
+pub fn main() {
+    let mut cat = Cat {
+        name: Name("Tom".to_owned()),
+        voice: Voice("meow"),
+    };
+    cat.rename("Felix");
+    assert_eq!(cat.greet(), "Felix says meow");
+
+    let dog = Dog(Name("Rex".to_owned()), Voice("wuff"));
+    assert_eq!(<Dog as Legs>::LEGS, 4);
+    assert_eq!(dog.legs(), 4);
+    assert_eq!(dog.describe(), "Rex with 0 legs");
+}
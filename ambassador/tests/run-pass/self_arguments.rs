@@ -0,0 +1,77 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, delegatable_trait_remote, Delegate};
+use std::ops::{Add, Mul};
+
+// `Rhs` defaults to `Self`, so it is declared as `Self` to have it delegated along with the receiver
+#[delegatable_trait_remote]
+trait Add {
+    type Output;
+    fn add(self, rhs: Self) -> Self::Output;
+}
+
+#[delegatable_trait_remote]
+trait Mul {
+    type Output;
+    fn mul(self, rhs: Self) -> Self::Output;
+}
+
+#[delegatable_trait]
+pub trait Combine {
+    fn combine(self, other: Self) -> Self;
+    fn same(&self, other: &Self) -> bool;
+    fn absorb(&mut self, other: &mut Self);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Money(u32);
+
+impl Combine for Money {
+    fn combine(self, other: Self) -> Self {
+        Money(self.0 + other.0)
+    }
+    fn same(&self, other: &Self) -> bool {
+        self == other
+    }
+    fn absorb(&mut self, other: &mut Self) {
+        self.0 += other.0;
+        other.0 = 0;
+    }
+}
+
+#[derive(Delegate, Clone, Copy)]
+#[delegate(Add)]
+#[delegate(Mul)]
+pub struct Meters(f64);
+
+#[derive(Delegate, Debug, PartialEq)]
+#[delegate(Combine, target = "money", from_inner = "|money| Wallet { money, owner: 0 }")]
+pub struct Wallet {
+    owner: u32,
+    money: Money,
+}
+
+pub fn main() {
+    assert_eq!(Meters(1.5) + Meters(2.0), 3.5);
+    assert_eq!(Meters(1.5) * Meters(2.0), 3.0);
+
+    let mut first = Wallet {
+        owner: 1,
+        money: Money(3),
+    };
+    let mut second = Wallet {
+        owner: 2,
+        money: Money(4),
+    };
+    assert!(!first.same(&second));
+    first.absorb(&mut second);
+    assert_eq!(first.money, Money(7));
+    assert_eq!(second.money, Money(0));
+    assert_eq!(
+        first.combine(second),
+        Wallet {
+            owner: 0,
+            money: Money(7)
+        }
+    );
+}
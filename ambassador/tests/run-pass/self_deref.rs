@@ -0,0 +1,69 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+use std::ops::{Deref, DerefMut};
+
+#[delegatable_trait]
+pub trait Shout {
+    type Voice;
+    fn shout(&self, input: &str) -> String;
+    fn rename(&mut self, name: &str);
+}
+
+pub struct Cat {
+    name: String,
+}
+
+impl Shout for Cat {
+    type Voice = u8;
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {} says meow!", input, self.name)
+    }
+    fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+}
+
+// The cat is only reachable through `Deref`, the field is never named
+#[derive(Delegate)]
+#[delegate(Shout, deref)]
+pub struct Pet {
+    animals: Vec<Cat>,
+    current: usize,
+}
+
+impl Deref for Pet {
+    type Target = Cat;
+    fn deref(&self) -> &Cat {
+        &self.animals[self.current]
+    }
+}
+
+impl DerefMut for Pet {
+    fn deref_mut(&mut self) -> &mut Cat {
+        &mut self.animals[self.current]
+    }
+}
+
+fn voice<T: Shout<Voice = u8>>(value: &T) -> String {
+    value.shout("voice")
+}
+
+pub fn main() {
+    let mut pet = Pet {
+        animals: vec![
+            Cat {
+                name: "Tom".to_string(),
+            },
+            Cat {
+                name: "Kitty".to_string(),
+            },
+        ],
+        current: 1,
+    };
+    assert_eq!(pet.shout("BAR"), "BAR - Kitty says meow!");
+    pet.rename("Felix");
+    pet.current = 0;
+    assert_eq!(voice(&pet), "voice - Tom says meow!");
+    assert_eq!(pet.animals[1].shout("BAR"), "BAR - Felix says meow!");
+}
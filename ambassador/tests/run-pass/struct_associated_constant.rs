@@ -0,0 +1,27 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Legs {
+    const NUM_LEGS: usize;
+    fn walk(&self) -> String;
+}
+
+pub struct Cat;
+
+impl Legs for Cat {
+    const NUM_LEGS: usize = 4;
+    fn walk(&self) -> String {
+        format!("walking on {} legs", Self::NUM_LEGS)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Legs)]
+pub struct WrappedCat(Cat);
+
+pub fn main() {
+    assert_eq!(WrappedCat::NUM_LEGS, 4);
+    assert_eq!(WrappedCat(Cat).walk(), "walking on 4 legs");
+}
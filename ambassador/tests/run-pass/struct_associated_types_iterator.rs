@@ -0,0 +1,26 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait_remote, Delegate};
+use std::iter::Iterator;
+
+#[delegatable_trait_remote]
+pub trait Iterator {
+    type Item;
+    fn next(&mut self) -> Option<Self::Item>;
+}
+
+#[derive(Delegate)]
+#[delegate(Iterator, target = "iter")]
+pub struct Countdown {
+    name: &'static str,
+    iter: std::iter::Rev<std::ops::Range<u32>>,
+}
+
+pub fn main() {
+    let countdown = Countdown {
+        name: "launch",
+        iter: (1..4).rev(),
+    };
+    assert_eq!(countdown.name, "launch");
+    assert_eq!(countdown.collect::<Vec<u32>>(), vec![3, 2, 1]);
+}
@@ -0,0 +1,52 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+#[delegatable_trait]
+pub trait Rename {
+    fn rename(&mut self, name: &str);
+}
+
+pub struct Cat {
+    name: String,
+}
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {} says meow!", input, self.name)
+    }
+}
+
+impl Rename for Cat {
+    fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "self.inner()")]
+#[delegate(Rename, target = "self.cats[0]")]
+pub struct Cats {
+    cats: Vec<Cat>,
+}
+
+impl Cats {
+    fn inner(&self) -> &Cat {
+        &self.cats[0]
+    }
+}
+
+pub fn main() {
+    let mut cats = Cats {
+        cats: vec![Cat {
+            name: "Tom".to_string(),
+        }],
+    };
+    cats.rename("Felix");
+    assert_eq!(cats.shout("BAR"), "BAR - Felix says meow!");
+}
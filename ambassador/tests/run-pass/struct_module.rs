@@ -0,0 +1,53 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+mod traits {
+    use ambassador::delegatable_trait;
+
+    #[delegatable_trait]
+    pub trait Name {
+        fn name(&self) -> String;
+    }
+}
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+impl traits::Name for Cat {
+    fn name(&self) -> String {
+        "Tom".to_string()
+    }
+}
+
+// Private types are visible from the module through `use super::*`
+#[derive(Delegate)]
+#[delegate(Shout, module = "shout_impl")]
+#[delegate(traits::Name, module = "name_impl")]
+struct WrappedCat(Cat);
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "inner", module = "shout_impl_generic")]
+struct Wrapper<T> {
+    inner: T,
+}
+
+pub fn main() {
+    use traits::Name;
+
+    let cat = WrappedCat(Cat);
+    assert_eq!(cat.shout("BAR"), "BAR - meow!");
+    assert_eq!(cat.name(), "Tom");
+    let wrapper = Wrapper { inner: cat };
+    assert_eq!(wrapper.shout("BAR"), "BAR - meow!");
+}
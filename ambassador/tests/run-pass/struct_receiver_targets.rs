@@ -0,0 +1,67 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+    fn rename(&mut self, name: &str);
+}
+
+pub struct Cat {
+    name: String,
+}
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {} says meow!", input, self.name)
+    }
+
+    fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target_ref = "self.inner()", target_mut = "self.inner_mut()")]
+pub struct BoxedCat(Box<Cat>);
+
+impl BoxedCat {
+    fn inner(&self) -> &Cat {
+        &self.0
+    }
+
+    fn inner_mut(&mut self) -> &mut Cat {
+        &mut self.0
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "cat", target_ref = "self.inner()")]
+pub struct CatAndName {
+    cat: Cat,
+    name: String,
+}
+
+impl CatAndName {
+    fn inner(&self) -> &Cat {
+        &self.cat
+    }
+}
+
+pub fn main() {
+    let mut boxed = BoxedCat(Box::new(Cat {
+        name: "Tom".to_string(),
+    }));
+    boxed.rename("Felix");
+    assert_eq!(boxed.shout("BAR"), "BAR - Felix says meow!");
+
+    let mut cat_and_name = CatAndName {
+        cat: Cat {
+            name: "Tom".to_string(),
+        },
+        name: "Felix".to_string(),
+    };
+    cat_and_name.rename(&cat_and_name.name.clone());
+    assert_eq!(cat_and_name.shout("BAR"), "BAR - Felix says meow!");
+}
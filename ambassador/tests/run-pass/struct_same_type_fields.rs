@@ -0,0 +1,54 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+#[delegatable_trait]
+pub trait Name {
+    fn name(&self) -> String;
+}
+
+pub struct Cat(&'static str);
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {} says meow!", input, self.0)
+    }
+}
+
+impl Name for Cat {
+    fn name(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+// Each impl only bounds the field type once, even though both fields have the same type
+#[derive(Delegate)]
+#[delegate(Shout, target = "0", where = "T: Shout")]
+#[delegate(Name, target = "1")]
+pub struct Pair<T>(T, T);
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "loud")]
+#[delegate(Name, target = "quiet")]
+pub struct Duo {
+    loud: Cat,
+    quiet: Cat,
+}
+
+pub fn main() {
+    let pair = Pair(Cat("Tom"), Cat("Kitty"));
+    assert_eq!(pair.shout("BAR"), "BAR - Tom says meow!");
+    assert_eq!(pair.name(), "Kitty");
+
+    let duo = Duo {
+        loud: Cat("Felix"),
+        quiet: Cat("Garfield"),
+    };
+    assert_eq!(duo.shout("BAR"), "BAR - Felix says meow!");
+    assert_eq!(duo.name(), "Garfield");
+}
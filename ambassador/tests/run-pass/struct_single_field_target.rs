@@ -0,0 +1,31 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "inner")]
+pub struct WrappedCat {
+    inner: Cat,
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "0")]
+pub struct TupleCat(Cat);
+
+pub fn main() {
+    assert_eq!(WrappedCat { inner: Cat }.shout("BAR"), "BAR - meow!");
+    assert_eq!(TupleCat(Cat).shout("BAR"), "BAR - meow!");
+}
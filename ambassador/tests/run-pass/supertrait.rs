@@ -0,0 +1,73 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Animal {
+    fn name(&self) -> String;
+}
+
+#[delegatable_trait]
+pub trait Pet: Animal {
+    fn greet(&self) -> String {
+        format!("Hi, I'm {}", self.name())
+    }
+    fn owner(&self) -> String;
+}
+
+#[delegatable_trait]
+pub trait ServiceDog: Pet {
+    fn task(&self) -> String;
+}
+
+pub struct Dog;
+
+impl Animal for Dog {
+    fn name(&self) -> String {
+        "Rex".to_string()
+    }
+}
+
+impl Pet for Dog {
+    fn owner(&self) -> String {
+        "Alice".to_string()
+    }
+}
+
+impl ServiceDog for Dog {
+    fn task(&self) -> String {
+        "guiding".to_string()
+    }
+}
+
+// `T: ServiceDog` implies `T: Pet` and `T: Animal`
+#[derive(Delegate)]
+#[delegate(Animal)]
+#[delegate(Pet)]
+#[delegate(ServiceDog)]
+pub struct Wrapper<T> {
+    inner: T,
+}
+
+#[derive(Delegate)]
+#[delegate(Animal)]
+#[delegate(Pet)]
+#[delegate(ServiceDog)]
+pub enum Either<A, B> {
+    Left(A),
+    Right(B),
+}
+
+fn describe<S: ServiceDog>(dog: &S) -> String {
+    format!("{} ({}) owned by {} is {}", dog.name(), dog.greet(), dog.owner(), dog.task())
+}
+
+pub fn main() {
+    let expected = "Rex (Hi, I'm Rex) owned by Alice is guiding";
+    assert_eq!(describe(&Wrapper { inner: Dog }), expected);
+    assert_eq!(describe(&Either::Left::<Dog, Wrapper<Dog>>(Dog)), expected);
+    let nested = Wrapper {
+        inner: Either::Right::<Dog, Wrapper<Dog>>(Wrapper { inner: Dog }),
+    };
+    assert_eq!(describe(&nested), expected);
+}
@@ -0,0 +1,47 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Named {
+    fn name(&self) -> String;
+}
+
+// A subtrait with a method of the same name as its supertrait's
+pub trait Titled: Named {
+    fn name(&self) -> String;
+    fn title(&self) -> String {
+        format!("Sir {}", Named::name(self))
+    }
+}
+
+pub struct Knight;
+
+impl Named for Knight {
+    fn name(&self) -> String {
+        "Arthur".to_string()
+    }
+}
+
+impl Titled for Knight {
+    fn name(&self) -> String {
+        self.title()
+    }
+}
+
+// With both traits in scope `self.0.name()` would be ambiguous,
+// so the method is called as `<Knight as Named>::name(&self.0)` instead
+#[derive(Delegate)]
+#[delegate(Named)]
+pub struct Squire(Knight);
+
+// Only the supertrait is delegated, so the field only has to implement it (`where Knight: Named`)
+fn named<T: Named>(named: &T) -> String {
+    named.name()
+}
+
+pub fn main() {
+    let squire = Squire(Knight);
+    assert_eq!(named(&squire), "Arthur");
+    assert_eq!(Titled::name(&squire.0), "Sir Arthur");
+}
@@ -0,0 +1,57 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+#[delegatable_trait]
+pub trait Name {
+    fn name(&self) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+pub struct Tag(String);
+
+impl Name for Tag {
+    fn name(&self) -> String {
+        self.0.clone()
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target_type = "Cat")]
+#[delegate(Name, target_type = "Tag")]
+pub struct Pet {
+    age: u32,
+    tag: Tag,
+    cat: Cat,
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target_type = "T", where = "T: Shout")]
+pub struct Tagged<T>(Tag, T);
+
+pub fn main() {
+    let pet = Pet {
+        age: 3,
+        tag: Tag("Tom".to_string()),
+        cat: Cat,
+    };
+    assert_eq!(pet.shout("BAR"), "BAR - meow!");
+    assert_eq!(pet.name(), "Tom");
+    assert_eq!(pet.age, 3);
+
+    let tagged = Tagged(Tag("Felix".to_string()), Cat);
+    assert_eq!(tagged.shout("BAR"), "BAR - meow!");
+    assert_eq!(tagged.0.name(), "Felix");
+}
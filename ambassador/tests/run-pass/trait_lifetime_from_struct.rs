@@ -0,0 +1,75 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, delegate_to_methods, Delegate};
+
+#[delegatable_trait]
+pub trait Reader<'a> {
+    fn next_word(&mut self) -> Option<&'a str>;
+    fn rest(&self) -> &'a str;
+}
+
+pub struct Words<'a> {
+    input: &'a str,
+}
+
+impl<'a> Reader<'a> for Words<'a> {
+    fn next_word(&mut self) -> Option<&'a str> {
+        let input = self.input.trim_start();
+        if input.is_empty() {
+            return None;
+        }
+        let end = input.find(' ').unwrap_or_else(|| input.len());
+        self.input = &input[end..];
+        Some(&input[..end])
+    }
+
+    fn rest(&self) -> &'a str {
+        self.input
+    }
+}
+
+// The lifetime in the trait path is the struct's own lifetime parameter
+#[derive(Delegate)]
+#[delegate(Reader<'a>)]
+pub struct Counting<'a> {
+    words: Words<'a>,
+}
+
+#[derive(Delegate)]
+#[delegate(Reader<'a>)]
+pub enum Either<'a> {
+    Words(Words<'a>),
+    Counting(Counting<'a>),
+}
+
+pub struct Boxed<'a>(Box<Words<'a>>);
+
+#[delegate_to_methods]
+#[delegate(Reader<'a>, target_ref = "inner", target_mut = "inner_mut")]
+impl<'a> Boxed<'a> {
+    fn inner(&self) -> &Words<'a> {
+        &self.0
+    }
+    fn inner_mut(&mut self) -> &mut Words<'a> {
+        &mut self.0
+    }
+}
+
+fn first_two<'a, R: Reader<'a>>(mut reader: R) -> (Option<&'a str>, Option<&'a str>, &'a str) {
+    (reader.next_word(), reader.next_word(), reader.rest())
+}
+
+pub fn main() {
+    let text = String::from("one two three");
+    let expected = (Some("one"), Some("two"), " three");
+    assert_eq!(first_two(Words { input: &text }), expected);
+    let counting = Counting {
+        words: Words { input: &text },
+    };
+    assert_eq!(first_two(counting), expected);
+    let either = Either::Counting(Counting {
+        words: Words { input: &text },
+    });
+    assert_eq!(first_two(either), expected);
+    assert_eq!(first_two(Boxed(Box::new(Words { input: &text }))), expected);
+}
@@ -0,0 +1,73 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+    fn rename(&mut self, name: &str);
+}
+
+pub struct Cat(String);
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - {} meows!", input, self.0)
+    }
+    fn rename(&mut self, name: &str) {
+        self.0 = name.to_owned();
+    }
+}
+
+// None of these implement `Shout` themselves, so no `Box<dyn Shout>: Shout` bound may be generated
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub struct BoxedAnimal(Box<dyn Shout>);
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "animal")]
+pub struct SendAnimal {
+    name: &'static str,
+    animal: Box<dyn Shout + Send>,
+}
+
+#[derive(Delegate)]
+#[delegate(Shout)]
+pub struct MutAnimal<'a>(&'a mut dyn Shout);
+
+#[delegatable_trait]
+pub trait Speak {
+    fn speak(&self) -> String;
+}
+
+impl Speak for Cat {
+    fn speak(&self) -> String {
+        self.0.clone()
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Speak)]
+pub struct BorrowedAnimal<'a>(&'a dyn Speak);
+
+pub fn main() {
+    let mut boxed = BoxedAnimal(Box::new(Cat("Tom".to_owned())));
+    boxed.rename("Felix");
+    assert_eq!(boxed.shout("BAR"), "BAR - Felix meows!");
+
+    let send = SendAnimal {
+        name: "send",
+        animal: Box::new(Cat("Tom".to_owned())),
+    };
+    assert_eq!(send.name, "send");
+    assert_eq!(send.shout("BAR"), "BAR - Tom meows!");
+
+    let mut cat = Cat("Tom".to_owned());
+    let mut borrowed = MutAnimal(&mut cat);
+    borrowed.rename("Felix");
+    assert_eq!(borrowed.shout("BAR"), "BAR - Felix meows!");
+
+    let borrowed = BorrowedAnimal(&cat);
+    assert_eq!(borrowed.speak(), "Felix");
+}
@@ -0,0 +1,46 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+pub struct Dog;
+
+impl Shout for Dog {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - wuff!", input)
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, target = "1")]
+pub struct QuotedIndex(Cat, Dog);
+
+#[derive(Delegate)]
+#[delegate(Shout, target = 1)]
+pub struct PlainIndex(Cat, Dog);
+
+#[derive(Delegate)]
+#[delegate(Shout, target = 0)]
+pub enum Animals {
+    Pair(Cat, Dog),
+    Single(Dog),
+}
+
+pub fn main() {
+    assert_eq!(QuotedIndex(Cat, Dog).shout("BAR"), "BAR - wuff!");
+    assert_eq!(PlainIndex(Cat, Dog).shout("BAR"), "BAR - wuff!");
+    assert_eq!(Animals::Pair(Cat, Dog).shout("BAR"), "BAR - meow!");
+    assert_eq!(Animals::Single(Dog).shout("BAR"), "BAR - wuff!");
+}
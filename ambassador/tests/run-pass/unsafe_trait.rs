@@ -0,0 +1,52 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, delegate_to_methods, Delegate};
+
+#[delegatable_trait]
+pub unsafe trait Zeroable {
+    fn is_zero(&self) -> bool;
+}
+
+pub struct Counter(u32);
+
+unsafe impl Zeroable for Counter {
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+#[derive(Delegate)]
+#[delegate(Zeroable, unsafe)]
+pub struct Wrapper(Counter);
+
+#[derive(Delegate)]
+#[delegate(Zeroable, unsafe)]
+pub enum Either {
+    Left(Counter),
+    Right(Wrapper),
+}
+
+pub struct Holder {
+    counter: Counter,
+}
+
+#[delegate_to_methods]
+#[delegate(Zeroable, unsafe, target_ref = "counter")]
+impl Holder {
+    fn counter(&self) -> &Counter {
+        &self.counter
+    }
+}
+
+fn is_zero<T: Zeroable>(value: &T) -> bool {
+    value.is_zero()
+}
+
+pub fn main() {
+    assert!(is_zero(&Wrapper(Counter(0))));
+    assert!(!is_zero(&Either::Right(Wrapper(Counter(1)))));
+    assert!(is_zero(&Either::Left(Counter(0))));
+    assert!(is_zero(&Holder {
+        counter: Counter(0)
+    }));
+}
@@ -0,0 +1,46 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, Delegate};
+
+// An unrelated trait that happens to be called `Default`, it is delegated like any other trait
+#[delegatable_trait]
+pub trait Default {
+    fn default() -> Self;
+    fn describe(&self) -> String;
+}
+
+pub struct Cat(String);
+
+impl Default for Cat {
+    fn default() -> Self {
+        Cat("Tom".to_string())
+    }
+    fn describe(&self) -> String {
+        format!("{} the cat", self.0)
+    }
+}
+
+// Doesn't implement `std::default::Default`
+pub struct Name(&'static str);
+
+#[derive(Delegate)]
+#[delegate(Default, target = "cat", from_inner = "Owner::with_cat")]
+pub struct Owner {
+    name: Name,
+    cat: Cat,
+}
+
+impl Owner {
+    fn with_cat(cat: Cat) -> Self {
+        Owner {
+            name: Name("Jon"),
+            cat,
+        }
+    }
+}
+
+pub fn main() {
+    let owner = <Owner as Default>::default();
+    assert_eq!(owner.name.0, "Jon");
+    assert_eq!(owner.describe(), "Tom the cat");
+}
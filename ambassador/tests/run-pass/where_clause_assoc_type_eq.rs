@@ -0,0 +1,42 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait_remote, delegate_to_methods, Delegate};
+
+#[delegatable_trait_remote]
+pub trait Iterator {
+    type Item;
+    fn next(&mut self) -> Option<Self::Item>;
+}
+
+// Only delegated for inner iterators over bytes
+#[derive(Delegate)]
+#[delegate(Iterator, where = "I: Iterator<Item = u8>")]
+pub struct Bytes<I>(I);
+
+#[derive(Delegate)]
+#[delegate(Iterator, where = "A: Iterator<Item = u8>, B: Iterator<Item = u8>")]
+pub enum Either<A, B> {
+    Left(A),
+    Right(B),
+}
+
+pub struct Boxed<I>(Box<I>);
+
+#[delegate_to_methods]
+#[delegate(Iterator, target_mut = "inner", where = "I: Iterator<Item = u8>")]
+impl<I> Boxed<I> {
+    fn inner(&mut self) -> &mut I {
+        &mut self.0
+    }
+}
+
+fn sum<I: Iterator<Item = u8>>(iter: I) -> u32 {
+    iter.map(u32::from).sum()
+}
+
+pub fn main() {
+    let bytes = vec![1u8, 2, 3];
+    assert_eq!(sum(Bytes(bytes.clone().into_iter())), 6);
+    assert_eq!(sum(Either::Right::<std::vec::IntoIter<u8>, _>(bytes.clone().into_iter())), 6);
+    assert_eq!(sum(Boxed(Box::new(bytes.into_iter()))), 6);
+}
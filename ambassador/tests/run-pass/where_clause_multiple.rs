@@ -0,0 +1,58 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, delegate_to_methods, Delegate};
+use std::fmt::Debug;
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+#[derive(Debug, Clone)]
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+// The repeated predicate and the one matching the automatic bound are only emitted once
+#[derive(Delegate)]
+#[delegate(
+    Shout,
+    where = "A: Debug",
+    where = "A: Clone, A: Debug",
+    where = "A: Shout"
+)]
+pub struct Wrapper<A>
+where
+    A: Clone,
+{
+    inner: A,
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, where = "A: Debug", where = "B: Debug", where = "A: Debug")]
+pub enum Either<A, B> {
+    Left(A),
+    Right(B),
+}
+
+pub struct Boxed<T>(Box<T>);
+
+#[delegate_to_methods]
+#[delegate(Shout, target_ref = "inner", where = "T: Debug", where = "T: Debug")]
+impl<T> Boxed<T> {
+    fn inner(&self) -> &T {
+        &self.0
+    }
+}
+
+pub fn main() {
+    let wrapper = Wrapper { inner: Cat };
+    assert_eq!(wrapper.shout("BAR"), "BAR - meow!");
+    let either = Either::Right::<Cat, Cat>(Cat);
+    assert_eq!(either.shout("BAR"), "BAR - meow!");
+    assert_eq!(Boxed(Box::new(Cat)).shout("BAR"), "BAR - meow!");
+}
@@ -0,0 +1,59 @@
+extern crate ambassador;
+
+use ambassador::{delegatable_trait, delegate_to_methods, Delegate};
+use std::fmt::Debug;
+
+#[delegatable_trait]
+pub trait Shout {
+    fn shout(&self, input: &str) -> String;
+}
+
+#[derive(Debug, Clone)]
+pub struct Cat;
+
+impl Shout for Cat {
+    fn shout(&self, input: &str) -> String {
+        format!("{} - meow!", input)
+    }
+}
+
+fn shout_sent<T: Shout + Send + 'static>(animal: T) -> String {
+    std::thread::spawn(move || animal.shout("BAR")).join().unwrap()
+}
+
+#[derive(Delegate)]
+#[delegate(Shout, where = "Self: Send")]
+pub struct Wrapper<T>(T);
+
+#[derive(Delegate, Clone)]
+#[delegate(Shout, where = "Self: Clone + Debug")]
+pub enum Either<A, B> {
+    Left(A),
+    Right(B),
+}
+
+pub struct Boxed<T>(Box<T>);
+
+#[delegate_to_methods]
+#[delegate(Shout, target_ref = "inner", where = "Self: Send")]
+impl<T> Boxed<T> {
+    fn inner(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<A: Debug, B: Debug> Debug for Either<A, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Either::Left(a) => a.fmt(f),
+            Either::Right(b) => b.fmt(f),
+        }
+    }
+}
+
+pub fn main() {
+    assert_eq!(shout_sent(Wrapper(Cat)), "BAR - meow!");
+    let either = Either::Left::<Cat, Cat>(Cat);
+    assert_eq!(either.clone().shout("BAR"), "BAR - meow!");
+    assert_eq!(Boxed(Box::new(Cat)).shout("BAR"), "BAR - meow!");
+}